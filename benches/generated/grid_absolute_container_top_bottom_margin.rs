@@ -27,7 +27,11 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![points(40f32), points(40f32), points(40f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(40f32),
+                    points(40f32),
+                    points(40f32),
+                ]),
                 padding: taffy::geometry::Rect {
                     left: taffy::style::LengthPercentage::Points(40f32),
                     right: taffy::style::LengthPercentage::Points(20f32),
@@ -23,7 +23,12 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), auto(), points(40f32), auto()],
-                grid_template_columns: vec![points(40f32), auto(), points(40f32), auto()],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(40f32),
+                    auto(),
+                    points(40f32),
+                    auto(),
+                ]),
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::Points(200f32),
                     height: taffy::style::Dimension::Points(200f32),
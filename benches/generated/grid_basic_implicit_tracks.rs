@@ -17,7 +17,7 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32)],
-                grid_template_columns: vec![points(40f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![points(40f32)]),
                 ..Default::default()
             },
             &[node0, node1],
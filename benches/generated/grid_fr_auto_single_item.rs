@@ -21,7 +21,7 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), flex(1f32), flex(1f32)],
-                grid_template_columns: vec![points(40f32), flex(1f32), flex(1f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![points(40f32), flex(1f32), flex(1f32)]),
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::Auto,
                     height: taffy::style::Dimension::Auto,
@@ -16,7 +16,11 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![points(40f32), minmax(points(20f32), points(40f32)), points(40f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(40f32),
+                    minmax(points(20f32), points(40f32)),
+                    points(40f32),
+                ]),
                 size: taffy::geometry::Size { width: taffy::style::Dimension::Points(110f32), height: auto() },
                 ..Default::default()
             },
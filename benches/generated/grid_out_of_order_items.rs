@@ -44,7 +44,11 @@ pub fn compute() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![points(40f32), points(40f32), points(40f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(40f32),
+                    points(40f32),
+                    points(40f32),
+                ]),
                 grid_auto_flow: taffy::style::GridAutoFlow::RowDense,
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::Points(120f32),
@@ -60,6 +60,9 @@ fn measure_standard_text(
         WritingMode::Vertical => Size { width: block_size, height: inline_size },
     }
 }
+mod absolute_align_self_stretch_between_insets;
+mod absolute_inset_auto_static_position;
+mod absolute_inset_mixed_auto;
 mod absolute_layout_align_items_and_justify_content_center;
 mod absolute_layout_align_items_and_justify_content_center_and_bottom_position;
 mod absolute_layout_align_items_and_justify_content_center_and_left_position;
@@ -85,6 +88,7 @@ mod align_baseline;
 mod align_baseline_child_multiline;
 mod align_baseline_nested_child;
 mod align_center_should_size_based_on_content;
+mod align_content_normal_flex_stretches_lines;
 mod align_content_space_around_single_line;
 mod align_content_space_around_wrapped;
 mod align_content_space_between_single_line;
@@ -94,6 +98,7 @@ mod align_content_space_evenly_wrapped;
 mod align_flex_start_with_shrinking_children;
 mod align_flex_start_with_shrinking_children_with_stretch;
 mod align_flex_start_with_stretching_children;
+mod align_items_baseline_column;
 mod align_items_center;
 mod align_items_center_child_with_margin_bigger_than_parent;
 mod align_items_center_child_without_margin_bigger_than_parent;
@@ -105,24 +110,30 @@ mod align_items_flex_end_child_without_margin_bigger_than_parent;
 mod align_items_flex_start;
 mod align_items_min_max;
 mod align_items_stretch;
+mod align_items_stretch_respects_explicit_cross_size;
 mod align_self_baseline;
 mod align_self_center;
 mod align_self_flex_end;
 mod align_self_flex_end_override_flex_start;
 mod align_self_flex_start;
 mod align_strech_should_size_based_on_parent;
+mod aspect_ratio_min_clamp_feedback;
 mod border_center_child;
 mod border_flex_child;
 mod border_no_child;
 mod border_stretch_child;
 mod child_min_max_width_flexing;
+mod column_auto_min_height_content;
 mod container_with_unsized_child;
+mod display_contents_flattens_children;
 mod display_none;
 mod display_none_fixed_size;
 mod display_none_with_child;
 mod display_none_with_margin;
 mod display_none_with_position;
+mod flex_base_and_final_double_clamp;
 mod flex_basis_and_main_dimen_set_when_flexing;
+mod flex_basis_auto_content_under_definite;
 mod flex_basis_flex_grow_column;
 mod flex_basis_flex_grow_row;
 mod flex_basis_flex_shrink_column;
@@ -130,6 +141,7 @@ mod flex_basis_flex_shrink_row;
 mod flex_basis_larger_than_content_column;
 mod flex_basis_larger_than_content_row;
 mod flex_basis_overrides_main_size;
+mod flex_basis_percent_column_resolves_against_height;
 mod flex_basis_slightly_smaller_then_content_with_flex_grow_large_size;
 mod flex_basis_smaller_than_content_column;
 mod flex_basis_smaller_than_content_row;
@@ -141,6 +153,7 @@ mod flex_basis_smaller_then_content_with_flex_grow_unconstraint_size;
 mod flex_basis_smaller_then_content_with_flex_grow_very_large_size;
 mod flex_basis_unconstraint_column;
 mod flex_basis_unconstraint_row;
+mod flex_basis_zero_percent_vs_zero_px;
 mod flex_direction_column;
 mod flex_direction_column_no_height;
 mod flex_direction_column_reverse;
@@ -149,7 +162,9 @@ mod flex_direction_row_no_width;
 mod flex_direction_row_reverse;
 mod flex_grow_child;
 mod flex_grow_flex_basis_percent_min_max;
+mod flex_grow_freeze_on_max_redistribute;
 mod flex_grow_height_maximized;
+mod flex_grow_ignored_under_max_content;
 mod flex_grow_in_at_most_container;
 mod flex_grow_less_than_factor_one;
 mod flex_grow_root_minimized;
@@ -166,9 +181,12 @@ mod flex_root_ignored;
 mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
+mod flex_shrink_freeze_redistribute;
+mod flex_shrink_scaled_by_basis;
 mod flex_shrink_to_zero;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
+mod flex_wrap_oversized_single_item_own_line;
 mod flex_wrap_wrap_to_child_height;
 mod gap_column_gap_child_margins;
 mod gap_column_gap_determines_parent_width;
@@ -196,6 +214,7 @@ mod gap_column_gap_wrap_align_space_around;
 mod gap_column_gap_wrap_align_space_between;
 mod gap_column_gap_wrap_align_stretch;
 mod gap_column_row_gap_wrapping;
+mod gap_excludes_absolute_children;
 mod gap_percentage_row_gap_wrapping;
 mod gap_row_gap_align_items_end;
 mod gap_row_gap_align_items_stretch;
@@ -249,8 +268,14 @@ mod grid_align_content_end;
 #[cfg(feature = "grid")]
 mod grid_align_content_end_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_align_content_normal_fixed_rows_start;
+#[cfg(feature = "grid")]
+mod grid_align_content_normal_stretches_auto_rows;
+#[cfg(feature = "grid")]
 mod grid_align_content_space_around;
 #[cfg(feature = "grid")]
+mod grid_align_content_space_around_single_track;
+#[cfg(feature = "grid")]
 mod grid_align_content_space_around_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_align_content_space_between;
@@ -273,6 +298,10 @@ mod grid_align_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_align_self_baseline_row;
+#[cfg(feature = "grid")]
+mod grid_align_self_overrides_align_items_stretch;
+#[cfg(feature = "grid")]
 mod grid_align_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_auto_columns_fixed_width;
@@ -283,6 +312,12 @@ mod grid_auto_fill_with_empty_auto_track;
 #[cfg(feature = "grid")]
 mod grid_auto_fit_with_empty_auto_track;
 #[cfg(feature = "grid")]
+mod grid_auto_place_span_two_column_dense;
+#[cfg(feature = "grid")]
+mod grid_auto_place_span_two_dense;
+#[cfg(feature = "grid")]
+mod grid_auto_rows_cycle;
+#[cfg(feature = "grid")]
 mod grid_auto_single_item;
 #[cfg(feature = "grid")]
 mod grid_auto_single_item_fixed_width;
@@ -297,6 +332,10 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_container_align_items_default;
+#[cfg(feature = "grid")]
+mod grid_fit_content_plus_fr;
+#[cfg(feature = "grid")]
 mod grid_fit_content_points_argument;
 #[cfg(feature = "grid")]
 mod grid_fit_content_points_max_content;
@@ -313,8 +352,12 @@ mod grid_fr_fixed_size_single_item;
 #[cfg(feature = "grid")]
 mod grid_gap;
 #[cfg(feature = "grid")]
+mod grid_gap_reserved_before_fr;
+#[cfg(feature = "grid")]
 mod grid_hidden;
 #[cfg(feature = "grid")]
+mod grid_item_aspect_ratio;
+#[cfg(feature = "grid")]
 mod grid_justify_content_center;
 #[cfg(feature = "grid")]
 mod grid_justify_content_center_with_padding_border;
@@ -333,12 +376,18 @@ mod grid_justify_content_space_between_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_evenly;
 #[cfg(feature = "grid")]
+mod grid_justify_content_space_evenly_single_track;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_evenly_with_padding_border;
 #[cfg(feature = "grid")]
 mod grid_justify_content_start;
 #[cfg(feature = "grid")]
+mod grid_justify_content_start_does_not_grow_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_content_stretch_grows_tracks;
+#[cfg(feature = "grid")]
 mod grid_justify_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_justify_items_sized_end;
@@ -403,6 +452,12 @@ mod grid_min_max_column_fixed_width_below_range;
 #[cfg(feature = "grid")]
 mod grid_min_max_column_fixed_width_within_range;
 #[cfg(feature = "grid")]
+mod grid_minmax_auto_fr;
+#[cfg(feature = "grid")]
+mod grid_negative_gap_clamped_to_zero;
+#[cfg(feature = "grid")]
+mod grid_order_affects_placement;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
 mod grid_percent_nested_moderate;
@@ -419,7 +474,13 @@ mod grid_placement_definite_in_secondary_axis_with_fully_definite_negative;
 #[cfg(feature = "grid")]
 mod grid_relayout_vertical_text;
 #[cfg(feature = "grid")]
+mod grid_root_max_size_clamps_under_max_content;
+#[cfg(feature = "grid")]
 mod grid_size_child_fixed_tracks;
+#[cfg(feature = "grid")]
+mod grid_subgrid_columns;
+#[cfg(feature = "grid")]
+mod grid_subgrid_columns_auto_fr;
 mod justify_content_column_center;
 mod justify_content_column_flex_end;
 mod justify_content_column_flex_start;
@@ -440,6 +501,8 @@ mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
 mod justify_content_row_space_between;
 mod justify_content_row_space_evenly;
+mod justify_content_stretch_flex_is_start;
+mod justify_content_stretch_row_reverse_is_start;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -491,6 +554,7 @@ mod measure_remeasure_child_after_stretching;
 mod measure_root;
 mod measure_stretch_overrides_measure;
 mod measure_width_overrides_measure;
+mod min_content_includes_padding_border;
 mod min_height;
 mod min_height_overrides_height;
 mod min_height_overrides_height_on_root;
@@ -500,8 +564,12 @@ mod min_width;
 mod min_width_overrides_max_width;
 mod min_width_overrides_width;
 mod min_width_overrides_width_on_root;
+mod negative_gap_clamped_to_zero;
+mod nested_flex_min_content_propagation;
 mod nested_overflowing_child;
 mod nested_overflowing_child_in_constraint_parent;
+mod overflow_auto_gutter_when_overflows;
+mod overflow_auto_no_gutter_when_fits;
 mod overflow_cross_axis;
 mod overflow_main_axis;
 mod padding_align_end_child;
@@ -511,6 +579,8 @@ mod padding_no_child;
 mod padding_stretch_child;
 mod parent_wrap_child_size_overflowing_parent;
 mod percent_absolute_position;
+mod percent_max_height_indefinite_parent_is_none;
+mod percent_size_against_content_box;
 mod percent_within_flex_grow;
 mod percentage_absolute_position;
 mod percentage_container_in_wrapping_container;
@@ -523,6 +593,8 @@ mod percentage_flex_basis_cross_min_width;
 mod percentage_flex_basis_main_max_height;
 mod percentage_flex_basis_main_max_width;
 mod percentage_flex_basis_main_min_width;
+mod percentage_gap_indefinite_parent_zero;
+mod percentage_margin_column_resolves_against_width;
 mod percentage_margin_should_calculate_based_only_on_width;
 mod percentage_moderate_complexity;
 mod percentage_multiple_nested_with_padding_margin_and_percentage_values;
@@ -533,7 +605,9 @@ mod percentage_size_based_on_parent_inner_size;
 mod percentage_size_of_flex_basis;
 mod percentage_width_height;
 mod percentage_width_height_undefined_parent_size;
+mod position_fixed_against_root;
 mod relative_position_should_not_nudge_siblings;
+mod root_max_size_clamps_under_max_content;
 mod rounding_flex_basis_flex_grow_row_prime_number_width;
 mod rounding_flex_basis_flex_grow_row_width_of_100;
 mod rounding_flex_basis_flex_shrink_row;
@@ -544,6 +618,7 @@ mod rounding_fractial_input_3;
 mod rounding_fractial_input_4;
 mod rounding_total_fractial;
 mod rounding_total_fractial_nested;
+mod scroll_container_auto_min_is_zero;
 mod size_defined_by_child;
 mod size_defined_by_child_with_border;
 mod size_defined_by_child_with_padding;
@@ -552,6 +627,8 @@ mod width_smaller_then_content_with_flex_grow_large_size;
 mod width_smaller_then_content_with_flex_grow_small_size;
 mod width_smaller_then_content_with_flex_grow_unconstraint_size;
 mod width_smaller_then_content_with_flex_grow_very_large_size;
+mod wrap_align_content_stretch_grows_lines;
+mod wrap_align_content_stretch_grows_lines_with_gap;
 mod wrap_column;
 mod wrap_nodes_with_content_sizing_margin_cross;
 mod wrap_nodes_with_content_sizing_overflowing_margin;
@@ -574,6 +651,9 @@ mod wrapped_row_within_align_items_flex_start;
 fn benchmark(c: &mut Criterion) {
     c.bench_function("generated benchmarks", |b| {
         b.iter(|| {
+            absolute_align_self_stretch_between_insets::compute();
+            absolute_inset_auto_static_position::compute();
+            absolute_inset_mixed_auto::compute();
             absolute_layout_align_items_and_justify_content_center::compute();
             absolute_layout_align_items_and_justify_content_center_and_bottom_position::compute();
             absolute_layout_align_items_and_justify_content_center_and_left_position::compute();
@@ -599,6 +679,7 @@ fn benchmark(c: &mut Criterion) {
             align_baseline_child_multiline::compute();
             align_baseline_nested_child::compute();
             align_center_should_size_based_on_content::compute();
+            align_content_normal_flex_stretches_lines::compute();
             align_content_space_around_single_line::compute();
             align_content_space_around_wrapped::compute();
             align_content_space_between_single_line::compute();
@@ -608,6 +689,7 @@ fn benchmark(c: &mut Criterion) {
             align_flex_start_with_shrinking_children::compute();
             align_flex_start_with_shrinking_children_with_stretch::compute();
             align_flex_start_with_stretching_children::compute();
+            align_items_baseline_column::compute();
             align_items_center::compute();
             align_items_center_child_with_margin_bigger_than_parent::compute();
             align_items_center_child_without_margin_bigger_than_parent::compute();
@@ -619,24 +701,30 @@ fn benchmark(c: &mut Criterion) {
             align_items_flex_start::compute();
             align_items_min_max::compute();
             align_items_stretch::compute();
+            align_items_stretch_respects_explicit_cross_size::compute();
             align_self_baseline::compute();
             align_self_center::compute();
             align_self_flex_end::compute();
             align_self_flex_end_override_flex_start::compute();
             align_self_flex_start::compute();
             align_strech_should_size_based_on_parent::compute();
+            aspect_ratio_min_clamp_feedback::compute();
             border_center_child::compute();
             border_flex_child::compute();
             border_no_child::compute();
             border_stretch_child::compute();
             child_min_max_width_flexing::compute();
+            column_auto_min_height_content::compute();
             container_with_unsized_child::compute();
+            display_contents_flattens_children::compute();
             display_none::compute();
             display_none_fixed_size::compute();
             display_none_with_child::compute();
             display_none_with_margin::compute();
             display_none_with_position::compute();
+            flex_base_and_final_double_clamp::compute();
             flex_basis_and_main_dimen_set_when_flexing::compute();
+            flex_basis_auto_content_under_definite::compute();
             flex_basis_flex_grow_column::compute();
             flex_basis_flex_grow_row::compute();
             flex_basis_flex_shrink_column::compute();
@@ -644,6 +732,7 @@ fn benchmark(c: &mut Criterion) {
             flex_basis_larger_than_content_column::compute();
             flex_basis_larger_than_content_row::compute();
             flex_basis_overrides_main_size::compute();
+            flex_basis_percent_column_resolves_against_height::compute();
             flex_basis_slightly_smaller_then_content_with_flex_grow_large_size::compute();
             flex_basis_smaller_than_content_column::compute();
             flex_basis_smaller_than_content_row::compute();
@@ -655,6 +744,7 @@ fn benchmark(c: &mut Criterion) {
             flex_basis_smaller_then_content_with_flex_grow_very_large_size::compute();
             flex_basis_unconstraint_column::compute();
             flex_basis_unconstraint_row::compute();
+            flex_basis_zero_percent_vs_zero_px::compute();
             flex_direction_column::compute();
             flex_direction_column_no_height::compute();
             flex_direction_column_reverse::compute();
@@ -663,7 +753,9 @@ fn benchmark(c: &mut Criterion) {
             flex_direction_row_reverse::compute();
             flex_grow_child::compute();
             flex_grow_flex_basis_percent_min_max::compute();
+            flex_grow_freeze_on_max_redistribute::compute();
             flex_grow_height_maximized::compute();
+            flex_grow_ignored_under_max_content::compute();
             flex_grow_in_at_most_container::compute();
             flex_grow_less_than_factor_one::compute();
             flex_grow_root_minimized::compute();
@@ -680,9 +772,12 @@ fn benchmark(c: &mut Criterion) {
             flex_shrink_by_outer_margin_with_max_size::compute();
             flex_shrink_flex_grow_child_flex_shrink_other_child::compute();
             flex_shrink_flex_grow_row::compute();
+            flex_shrink_freeze_redistribute::compute();
+            flex_shrink_scaled_by_basis::compute();
             flex_shrink_to_zero::compute();
             flex_wrap_align_stretch_fits_one_row::compute();
             flex_wrap_children_with_min_main_overriding_flex_basis::compute();
+            flex_wrap_oversized_single_item_own_line::compute();
             flex_wrap_wrap_to_child_height::compute();
             gap_column_gap_child_margins::compute();
             gap_column_gap_determines_parent_width::compute();
@@ -710,6 +805,7 @@ fn benchmark(c: &mut Criterion) {
             gap_column_gap_wrap_align_space_between::compute();
             gap_column_gap_wrap_align_stretch::compute();
             gap_column_row_gap_wrapping::compute();
+            gap_excludes_absolute_children::compute();
             gap_percentage_row_gap_wrapping::compute();
             gap_row_gap_align_items_end::compute();
             gap_row_gap_align_items_stretch::compute();
@@ -763,8 +859,14 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_align_content_end_with_padding_border::compute();
             #[cfg(feature = "grid")]
+            grid_align_content_normal_fixed_rows_start::compute();
+            #[cfg(feature = "grid")]
+            grid_align_content_normal_stretches_auto_rows::compute();
+            #[cfg(feature = "grid")]
             grid_align_content_space_around::compute();
             #[cfg(feature = "grid")]
+            grid_align_content_space_around_single_track::compute();
+            #[cfg(feature = "grid")]
             grid_align_content_space_around_with_padding_border::compute();
             #[cfg(feature = "grid")]
             grid_align_content_space_between::compute();
@@ -787,6 +889,10 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_align_items_sized_stretch::compute();
             #[cfg(feature = "grid")]
+            grid_align_self_baseline_row::compute();
+            #[cfg(feature = "grid")]
+            grid_align_self_overrides_align_items_stretch::compute();
+            #[cfg(feature = "grid")]
             grid_align_self_sized_all::compute();
             #[cfg(feature = "grid")]
             grid_auto_columns_fixed_width::compute();
@@ -797,6 +903,12 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_auto_fit_with_empty_auto_track::compute();
             #[cfg(feature = "grid")]
+            grid_auto_place_span_two_column_dense::compute();
+            #[cfg(feature = "grid")]
+            grid_auto_place_span_two_dense::compute();
+            #[cfg(feature = "grid")]
+            grid_auto_rows_cycle::compute();
+            #[cfg(feature = "grid")]
             grid_auto_single_item::compute();
             #[cfg(feature = "grid")]
             grid_auto_single_item_fixed_width::compute();
@@ -811,6 +923,10 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_basic_with_padding::compute();
             #[cfg(feature = "grid")]
+            grid_container_align_items_default::compute();
+            #[cfg(feature = "grid")]
+            grid_fit_content_plus_fr::compute();
+            #[cfg(feature = "grid")]
             grid_fit_content_points_argument::compute();
             #[cfg(feature = "grid")]
             grid_fit_content_points_max_content::compute();
@@ -827,8 +943,12 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_gap::compute();
             #[cfg(feature = "grid")]
+            grid_gap_reserved_before_fr::compute();
+            #[cfg(feature = "grid")]
             grid_hidden::compute();
             #[cfg(feature = "grid")]
+            grid_item_aspect_ratio::compute();
+            #[cfg(feature = "grid")]
             grid_justify_content_center::compute();
             #[cfg(feature = "grid")]
             grid_justify_content_center_with_padding_border::compute();
@@ -847,12 +967,18 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_justify_content_space_evenly::compute();
             #[cfg(feature = "grid")]
+            grid_justify_content_space_evenly_single_track::compute();
+            #[cfg(feature = "grid")]
             grid_justify_content_space_evenly_with_padding_border::compute();
             #[cfg(feature = "grid")]
             grid_justify_content_start::compute();
             #[cfg(feature = "grid")]
+            grid_justify_content_start_does_not_grow_tracks::compute();
+            #[cfg(feature = "grid")]
             grid_justify_content_start_with_padding_border::compute();
             #[cfg(feature = "grid")]
+            grid_justify_content_stretch_grows_tracks::compute();
+            #[cfg(feature = "grid")]
             grid_justify_items_sized_center::compute();
             #[cfg(feature = "grid")]
             grid_justify_items_sized_end::compute();
@@ -917,6 +1043,12 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_min_max_column_fixed_width_within_range::compute();
             #[cfg(feature = "grid")]
+            grid_minmax_auto_fr::compute();
+            #[cfg(feature = "grid")]
+            grid_negative_gap_clamped_to_zero::compute();
+            #[cfg(feature = "grid")]
+            grid_order_affects_placement::compute();
+            #[cfg(feature = "grid")]
             grid_out_of_order_items::compute();
             #[cfg(feature = "grid")]
             grid_percent_nested_moderate::compute();
@@ -933,7 +1065,13 @@ fn benchmark(c: &mut Criterion) {
             #[cfg(feature = "grid")]
             grid_relayout_vertical_text::compute();
             #[cfg(feature = "grid")]
+            grid_root_max_size_clamps_under_max_content::compute();
+            #[cfg(feature = "grid")]
             grid_size_child_fixed_tracks::compute();
+            #[cfg(feature = "grid")]
+            grid_subgrid_columns::compute();
+            #[cfg(feature = "grid")]
+            grid_subgrid_columns_auto_fr::compute();
             justify_content_column_center::compute();
             justify_content_column_flex_end::compute();
             justify_content_column_flex_start::compute();
@@ -954,6 +1092,8 @@ fn benchmark(c: &mut Criterion) {
             justify_content_row_space_around::compute();
             justify_content_row_space_between::compute();
             justify_content_row_space_evenly::compute();
+            justify_content_stretch_flex_is_start::compute();
+            justify_content_stretch_row_reverse_is_start::compute();
             margin_and_flex_column::compute();
             margin_and_flex_row::compute();
             margin_and_stretch_column::compute();
@@ -1005,6 +1145,7 @@ fn benchmark(c: &mut Criterion) {
             measure_root::compute();
             measure_stretch_overrides_measure::compute();
             measure_width_overrides_measure::compute();
+            min_content_includes_padding_border::compute();
             min_height::compute();
             min_height_overrides_height::compute();
             min_height_overrides_height_on_root::compute();
@@ -1014,8 +1155,12 @@ fn benchmark(c: &mut Criterion) {
             min_width_overrides_max_width::compute();
             min_width_overrides_width::compute();
             min_width_overrides_width_on_root::compute();
+            negative_gap_clamped_to_zero::compute();
+            nested_flex_min_content_propagation::compute();
             nested_overflowing_child::compute();
             nested_overflowing_child_in_constraint_parent::compute();
+            overflow_auto_gutter_when_overflows::compute();
+            overflow_auto_no_gutter_when_fits::compute();
             overflow_cross_axis::compute();
             overflow_main_axis::compute();
             padding_align_end_child::compute();
@@ -1025,6 +1170,8 @@ fn benchmark(c: &mut Criterion) {
             padding_stretch_child::compute();
             parent_wrap_child_size_overflowing_parent::compute();
             percent_absolute_position::compute();
+            percent_max_height_indefinite_parent_is_none::compute();
+            percent_size_against_content_box::compute();
             percent_within_flex_grow::compute();
             percentage_absolute_position::compute();
             percentage_container_in_wrapping_container::compute();
@@ -1037,6 +1184,8 @@ fn benchmark(c: &mut Criterion) {
             percentage_flex_basis_main_max_height::compute();
             percentage_flex_basis_main_max_width::compute();
             percentage_flex_basis_main_min_width::compute();
+            percentage_gap_indefinite_parent_zero::compute();
+            percentage_margin_column_resolves_against_width::compute();
             percentage_margin_should_calculate_based_only_on_width::compute();
             percentage_moderate_complexity::compute();
             percentage_multiple_nested_with_padding_margin_and_percentage_values::compute();
@@ -1047,7 +1196,9 @@ fn benchmark(c: &mut Criterion) {
             percentage_size_of_flex_basis::compute();
             percentage_width_height::compute();
             percentage_width_height_undefined_parent_size::compute();
+            position_fixed_against_root::compute();
             relative_position_should_not_nudge_siblings::compute();
+            root_max_size_clamps_under_max_content::compute();
             rounding_flex_basis_flex_grow_row_prime_number_width::compute();
             rounding_flex_basis_flex_grow_row_width_of_100::compute();
             rounding_flex_basis_flex_shrink_row::compute();
@@ -1058,6 +1209,7 @@ fn benchmark(c: &mut Criterion) {
             rounding_fractial_input_4::compute();
             rounding_total_fractial::compute();
             rounding_total_fractial_nested::compute();
+            scroll_container_auto_min_is_zero::compute();
             size_defined_by_child::compute();
             size_defined_by_child_with_border::compute();
             size_defined_by_child_with_padding::compute();
@@ -1066,6 +1218,8 @@ fn benchmark(c: &mut Criterion) {
             width_smaller_then_content_with_flex_grow_small_size::compute();
             width_smaller_then_content_with_flex_grow_unconstraint_size::compute();
             width_smaller_then_content_with_flex_grow_very_large_size::compute();
+            wrap_align_content_stretch_grows_lines::compute();
+            wrap_align_content_stretch_grows_lines_with_gap::compute();
             wrap_column::compute();
             wrap_nodes_with_content_sizing_margin_cross::compute();
             wrap_nodes_with_content_sizing_overflowing_margin::compute();
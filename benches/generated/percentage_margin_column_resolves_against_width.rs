@@ -0,0 +1,46 @@
+pub fn compute() {
+    #[allow(unused_imports)]
+    use taffy::prelude::*;
+    let mut taffy = taffy::Taffy::new();
+    let node00 = taffy
+        .new_leaf(taffy::style::Style {
+            size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::Points(10f32) },
+            ..Default::default()
+        })
+        .unwrap();
+    let node0 = taffy
+        .new_with_children(
+            taffy::style::Style {
+                size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::Points(50f32) },
+                margin: taffy::geometry::Rect {
+                    left: taffy::style::LengthPercentageAuto::Percent(0.1f32),
+                    right: taffy::style::LengthPercentageAuto::Points(0f32),
+                    top: taffy::style::LengthPercentageAuto::Percent(0.1f32),
+                    bottom: taffy::style::LengthPercentageAuto::Points(0f32),
+                },
+                padding: taffy::geometry::Rect {
+                    left: taffy::style::LengthPercentage::Points(0f32),
+                    right: taffy::style::LengthPercentage::Points(0f32),
+                    top: taffy::style::LengthPercentage::Percent(0.1f32),
+                    bottom: taffy::style::LengthPercentage::Points(0f32),
+                },
+                ..Default::default()
+            },
+            &[node00],
+        )
+        .unwrap();
+    let node = taffy
+        .new_with_children(
+            taffy::style::Style {
+                flex_direction: taffy::style::FlexDirection::Column,
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Points(200f32),
+                    height: taffy::style::Dimension::Points(400f32),
+                },
+                ..Default::default()
+            },
+            &[node0],
+        )
+        .unwrap();
+    taffy.compute_layout(node, taffy::geometry::Size::MAX_CONTENT).unwrap();
+}
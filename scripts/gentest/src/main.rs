@@ -351,6 +351,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         Value::String(ref value) => match value.as_ref() {
             "none" => quote!(display: taffy::style::Display::None,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
+            "contents" => quote!(display: taffy::style::Display::Contents,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
         _ => quote!(),
@@ -359,6 +360,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let position = match style["position"] {
         Value::String(ref value) => match value.as_ref() {
             "absolute" => quote!(position: taffy::style::Position::Absolute,),
+            "fixed" => quote!(position: taffy::style::Position::Fixed,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -392,13 +394,22 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         _ => quote!(),
     };
 
-    let overflow = match style["overflow"] {
+    let overflow_axis = |key: &str| match style[key] {
         Value::String(ref value) => match value.as_ref() {
-            "hidden" => quote!(overflow: taffy::style::Overflow::Hidden,),
-            "scroll" => quote!(overflow: taffy::style::Overflow::Scroll,),
-            _ => quote!(),
+            "hidden" => Some(quote!(taffy::style::Overflow::Hidden)),
+            "scroll" => Some(quote!(taffy::style::Overflow::Scroll)),
+            "auto" => Some(quote!(taffy::style::Overflow::Auto)),
+            _ => None,
         },
-        _ => quote!(),
+        _ => None,
+    };
+    let overflow = match (overflow_axis("overflowX"), overflow_axis("overflowY")) {
+        (None, None) => quote!(),
+        (x, y) => {
+            let x = x.unwrap_or(quote!(taffy::style::Overflow::Visible));
+            let y = y.unwrap_or(quote!(taffy::style::Overflow::Visible));
+            quote!(overflow: taffy::geometry::Point { x: #x, y: #y },)
+        }
     };
 
     let align_items = match style["alignItems"] {
@@ -477,6 +488,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         _ => quote!(),
     };
 
+    let scrollbar_width = quote_number_prop("scrollbar_width", style, |value: f32| quote!(#value));
     let flex_grow = quote_number_prop("flex_grow", style, |value: f32| quote!(#value));
     let flex_shrink = quote_number_prop("flex_shrink", style, |value: f32| quote!(#value));
 
@@ -484,11 +496,18 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let size = quote_object_prop("size", style, generate_size);
     let min_size = quote_object_prop("min_size", style, generate_size);
     let max_size = quote_object_prop("max_size", style, generate_size);
+    let aspect_ratio = quote_number_prop("aspect_ratio", style, |value: f32| quote!(Some(#value)));
 
     let gap = quote_object_prop("gap", style, generate_gap);
 
     let grid_template_rows = quote_array_prop("grid_template_rows", style, generate_track_definition_list);
-    let grid_template_columns = quote_array_prop("grid_template_columns", style, generate_track_definition_list);
+    let grid_template_columns = match style["subgridColumns"] {
+        Value::Bool(true) => quote!(grid_template_columns: taffy::style::GridTemplate::Subgrid,),
+        _ => quote_array_prop("grid_template_columns", style, |value| {
+            let tracks = generate_track_definition_list(value);
+            quote!(taffy::style::GridTemplate::Tracks(#tracks))
+        }),
+    };
     let grid_auto_rows = quote_array_prop("grid_auto_rows", style, generate_track_definition_list);
     let grid_auto_columns = quote_array_prop("grid_auto_columns", style, generate_track_definition_list);
     let grid_auto_flow = quote_object_prop("grid_auto_flow", style, generate_grid_auto_flow);
@@ -523,6 +542,14 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         quote!()
     };
 
+    let order = match style["order"] {
+        Value::Number(ref value) => {
+            let value = value.as_i64().unwrap() as i32;
+            quote!(order: #value,)
+        }
+        _ => quote!(),
+    };
+
     let text_content = get_string_value("text_content", node);
     let writing_mode = get_string_value("writingMode", style);
     let measure_func: Option<_> = text_content.map(|text| generate_measure_function(text, writing_mode));
@@ -563,6 +590,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         #flex_direction
         #flex_wrap
         #overflow
+        #scrollbar_width
         #align_items
         #align_self
         #justify_items
@@ -580,9 +608,11 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         #grid_auto_flow
         #grid_row
         #grid_column
+        #order
         #size
         #min_size
         #max_size
+        #aspect_ratio
         #margin
         #padding
         #inset
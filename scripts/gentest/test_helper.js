@@ -103,6 +103,13 @@ function parseNumber(input) {
   return Number(input);
 }
 
+function parseAspectRatio(input) {
+  if (!input || input === 'auto') return undefined;
+  const [width, height = '1'] = input.split('/').map(part => part.trim());
+  const ratio = Number(width) / Number(height);
+  return isFinite(ratio) && ratio > 0 ? ratio : undefined;
+}
+
 function parseEnum(input) {
   if (input) return input;
   return undefined;
@@ -170,7 +177,10 @@ function describeElement(e) {
       writingMode: parseEnum(e.style.writingMode),
 
       flexWrap: parseEnum(e.style.flexWrap),
-      overflow: parseEnum(e.style.overflow),
+      overflowX: parseEnum(e.style.overflowX),
+      overflowY: parseEnum(e.style.overflowY),
+      // Scrollbar sizes can't be read from the DOM, so fixtures should set this to match their ::-webkit-scrollbar styles
+      scrollbarWidth: parseNumber(e.style.getPropertyValue('--scrollbar-width')),
 
       alignItems: parseEnum(e.style.alignItems),
       alignSelf: parseEnum(e.style.alignSelf),
@@ -185,7 +195,8 @@ function describeElement(e) {
       flexBasis: parseDimension(e.style.flexBasis),
 
       gridTemplateRows: parseGridTrackDefinitions(e.style.gridTemplateRows),
-      gridTemplateColumns: parseGridTrackDefinitions(e.style.gridTemplateColumns),
+      gridTemplateColumns: e.style.gridTemplateColumns.trim() === 'subgrid' ? undefined : parseGridTrackDefinitions(e.style.gridTemplateColumns),
+      subgridColumns: e.style.gridTemplateColumns.trim() === 'subgrid' ? true : undefined,
       gridAutoRows: parseGridTrackDefinitions(e.style.gridAutoRows),
       gridAutoColumns: parseGridTrackDefinitions(e.style.gridAutoColumns),
       gridAutoFlow: parseGridAutoFlow(e.style.gridAutoFlow),
@@ -194,12 +205,14 @@ function describeElement(e) {
       gridRowEnd: parseGridPosition(e.style.gridRowEnd),
       gridColumnStart: parseGridPosition(e.style.gridColumnStart),
       gridColumnEnd: parseGridPosition(e.style.gridColumnEnd),
+      order: e.style.order === '' ? undefined : parseInt(e.style.order, 10),
 
       gap: parseGaps(e.style),
 
       size: parseSize({width: e.style.width, height: e.style.height}),
       minSize: parseSize({width: e.style.minWidth, height: e.style.minHeight}),
       maxSize: parseSize({width: e.style.maxWidth, height: e.style.maxHeight}),
+      aspectRatio: parseAspectRatio(e.style.aspectRatio),
 
       margin: parseEdges({
         left: e.style.marginLeft,
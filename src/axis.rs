@@ -66,6 +66,7 @@ impl AbstractAxis {
 
 /// Container that holds an item in each absolute axis without specifying
 /// what kind of item it is.
+#[cfg(feature = "grid")]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) struct InBothAbsAxis<T> {
     /// The item in the horizontal axis
@@ -74,6 +75,7 @@ pub(crate) struct InBothAbsAxis<T> {
     pub vertical: T,
 }
 
+#[cfg(feature = "grid")]
 impl<T: Copy> InBothAbsAxis<T> {
     /// Get the contained item based on the AbsoluteAxis passed
     pub fn get(&self, axis: AbsoluteAxis) -> T {
@@ -1,5 +1,20 @@
 //! Generic CSS alignment code that is shared between both the Flexbox and CSS Grid algorithms.
+use crate::layout::Layout;
+use crate::node::Node;
 use crate::style::AlignContent;
+use crate::tree::LayoutTree;
+
+/// Computes the baseline of a node that has been laid out with the given `layout`, measured from its top edge
+///
+/// The baseline of a node is that of its first child, or the bottom edge of the node if it has no children.
+pub(crate) fn compute_baseline(tree: &impl LayoutTree, node: Node, layout: &Layout) -> f32 {
+    if let Some(first_child) = tree.children(node).next() {
+        let layout = tree.layout(*first_child);
+        compute_baseline(tree, *first_child, layout)
+    } else {
+        layout.size.height
+    }
+}
 
 /// Generic alignment function that is used:
 ///   - For both align-content and justify-content alignment
@@ -15,7 +30,8 @@ pub(crate) fn compute_alignment_offset(
     is_first: bool,
 ) -> f32 {
     match alignment_mode {
-        AlignContent::Start => {
+        // Stretch has no meaning here (any stretching happens before alignment), so it behaves as start
+        AlignContent::Start | AlignContent::Stretch => {
             if is_first {
                 if layout_is_reversed {
                     free_space
@@ -44,13 +60,6 @@ pub(crate) fn compute_alignment_offset(
                 gap
             }
         }
-        AlignContent::Stretch => {
-            if is_first {
-                0.0
-            } else {
-                gap
-            }
-        }
         AlignContent::SpaceBetween => {
             if is_first {
                 0.0
@@ -3,21 +3,22 @@
 //! Note that some minor steps appear to be missing: see https://github.com/DioxusLabs/taffy/issues for more information.
 use core::f32;
 
-use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::alignment::{compute_alignment_offset, compute_baseline};
 use crate::compute::compute_node_layout;
 use crate::geometry::{Point, Rect, Size};
-use crate::layout::{Layout, RunMode, SizingMode};
+use crate::layout::{Layout, LayoutWarning, RunMode, SizingMode};
 use crate::math::MaybeMath;
 use crate::node::Node;
 use crate::prelude::{TaffyMaxContent, TaffyMinContent};
 use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style::{
     AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexWrap, JustifyContent,
-    LengthPercentageAuto, Position,
+    LengthPercentage, LengthPercentageAuto, Overflow,
 };
 use crate::style::{FlexDirection, Style};
-use crate::sys::Vec;
+use crate::sys::{new_vec_with_capacity, round, Vec};
 use crate::tree::LayoutTree;
+use crate::util::resolve_gap;
 
 #[cfg(feature = "debug")]
 use crate::debug::NODE_LOGGER;
@@ -26,6 +27,8 @@ use crate::debug::NODE_LOGGER;
 struct FlexItem {
     /// The identifier for the associated [`Node`](crate::node::Node)
     node: Node,
+    /// The index of the node among its siblings
+    order: u32,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -185,16 +188,61 @@ fn compute_preliminary(
     run_mode: RunMode,
 ) -> Size<f32> {
     // Define some general constants we will need for the remainder of the algorithm.
-    let mut constants = compute_constants(tree.style(node), known_dimensions, parent_size);
+    let constants = compute_constants(tree.style(node), known_dimensions, parent_size);
+    let (size, content_overflow) =
+        compute_with_constants(tree, node, known_dimensions, available_space, run_mode, constants);
 
+    // Containers with `overflow: auto` only reserve space for a scrollbar in an axis if their content overflows in
+    // that axis. We can only know this once the content has been laid out, so if it does overflow we lay out again.
+    let style = tree.style(node);
+    let needs_auto_scrollbar = Point {
+        x: style.overflow.x == Overflow::Auto && content_overflow.x,
+        y: style.overflow.y == Overflow::Auto && content_overflow.y,
+    };
+    if style.scrollbar_width > 0.0 && (needs_auto_scrollbar.x || needs_auto_scrollbar.y) {
+        #[cfg(feature = "debug")]
+        NODE_LOGGER.log("FLEX: reserving space for auto scrollbars");
+
+        // A vertical scrollbar takes up horizontal space and vice versa
+        let scrollbar_gutter = Size {
+            width: if needs_auto_scrollbar.y { style.scrollbar_width } else { 0.0 },
+            height: if needs_auto_scrollbar.x { style.scrollbar_width } else { 0.0 },
+        };
+        let mut constants = compute_constants(style, known_dimensions, parent_size);
+        reserve_scrollbar_gutter(&mut constants, scrollbar_gutter);
+        return compute_with_constants(tree, node, known_dimensions, available_space, run_mode, constants).0;
+    }
+
+    size
+}
+
+/// Runs the flexbox algorithm using the provided constants
+///
+/// Returns the size of the container and whether the content overflowed the container in each axis.
+fn compute_with_constants(
+    tree: &mut impl LayoutTree,
+    node: Node,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    mut constants: AlgoConstants,
+) -> (Size<f32>, Point<bool>) {
     // 9. Flex Layout Algorithm
 
     // 9.1. Initial Setup
 
+    // Children of `Display::Contents` nodes are laid out as if they were children of this node
+    let mut children = new_vec_with_capacity(tree.child_count(node));
+    collect_children(tree, node, &mut children);
+
     // 1. Generate anonymous flex items as described in §4 Flex Items.
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("generate_anonymous_flex_items");
-    let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
+    let mut flex_items = generate_anonymous_flex_items(tree, &children, &constants);
+
+    if run_mode == RunMode::PeformLayout {
+        record_cyclic_percentage_warnings(tree, node, &flex_items, &constants);
+    }
 
     // 9.2. Line Length Determination
 
@@ -203,12 +251,12 @@ fn compute_preliminary(
     NODE_LOGGER.log("determine_available_space");
     let available_space = determine_available_space(known_dimensions, available_space, &constants);
 
-    let has_baseline_child = flex_items.iter().any(|child| child.align_self == AlignSelf::Baseline);
+    let has_baseline_child = constants.is_row && flex_items.iter().any(|child| child.align_self == AlignSelf::Baseline);
 
     // 3. Determine the flex base size and hypothetical main size of each item.
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("determine_flex_base_size");
-    determine_flex_base_size(tree, known_dimensions, &constants, available_space, &mut flex_items);
+    determine_flex_base_size(tree, &constants, available_space, &mut flex_items);
 
     #[cfg(feature = "debug")]
     for item in flex_items.iter() {
@@ -236,7 +284,7 @@ fn compute_preliminary(
         });
 
         let style = tree.style(node);
-        let new_gap = style.gap.main(constants.dir).maybe_resolve(longest_line_length).unwrap_or(0.0);
+        let new_gap = style.gap.main(constants.dir).maybe_resolve(longest_line_length).unwrap_or(0.0).max(0.0);
         constants.gap.set_main(constants.dir, new_gap);
     }
 
@@ -281,7 +329,7 @@ fn compute_preliminary(
     if has_baseline_child {
         #[cfg(feature = "debug")]
         NODE_LOGGER.log("calculate_children_base_lines");
-        calculate_children_base_lines(tree, node, known_dimensions, available_space, &mut flex_lines, &constants);
+        calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, &constants);
     }
 
     // 8. Calculate the cross size of each flex line.
@@ -332,12 +380,13 @@ fn compute_preliminary(
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("determine_container_cross_size");
     let total_line_cross_size = determine_container_cross_size(&mut flex_lines, known_dimensions, &mut constants);
+    let content_overflow = determine_content_overflow(&flex_lines, &constants);
 
     // We have the container size.
     // If our caller does not care about performing layout we are done now.
     if run_mode == RunMode::ComputeSize {
         let container_size = constants.container_size;
-        return container_size;
+        return (container_size, content_overflow);
     }
 
     // 16. Align all flex lines per align-content.
@@ -348,33 +397,47 @@ fn compute_preliminary(
     // Do a final layout pass and gather the resulting layouts
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("final_layout_pass");
-    final_layout_pass(tree, node, &mut flex_lines, &constants);
+    final_layout_pass(tree, &mut flex_lines, &constants);
+
+    // Record which flex line each child was placed on. Children that are not flex items are not on any line.
+    for &(_, child) in children.iter() {
+        tree.record_flex_line(child, None);
+    }
+    for (index, line) in flex_lines.iter().enumerate() {
+        for item in line.items.iter() {
+            tree.record_flex_line(item.node, Some(index));
+        }
+    }
 
     // Before returning we perform absolute layout on all absolutely positioned children
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("perform_absolute_layout_on_absolute_children");
-    perform_absolute_layout_on_absolute_children(tree, node, &constants);
+    perform_absolute_layout_on_absolute_children(tree, node, &children, &constants);
 
     #[cfg(feature = "debug")]
     NODE_LOGGER.log("hidden_layout");
-    let len = tree.child_count(node);
-    for order in 0..len {
-        let child = tree.child(node, order);
-        if tree.style(child).display == Display::None {
-            *tree.layout_mut(node) = Layout::with_order(order as u32);
-            compute_node_layout(
-                tree,
-                child,
-                Size::NONE,
-                Size::NONE,
-                Size::MAX_CONTENT,
-                RunMode::PeformLayout,
-                SizingMode::InherentSize,
-            );
+    for &(order, child) in children.iter() {
+        match tree.style(child).display {
+            Display::None => {
+                *tree.layout_mut(child) = Layout::with_order(order);
+                compute_node_layout(
+                    tree,
+                    child,
+                    Size::NONE,
+                    Size::NONE,
+                    Size::MAX_CONTENT,
+                    RunMode::PeformLayout,
+                    SizingMode::InherentSize,
+                );
+            }
+            // Contents nodes do not generate a box, so they are given a zero-sized layout at the origin of this node
+            // (which means that their children's locations are also relative to this node)
+            Display::Contents => *tree.layout_mut(child) = Layout::with_order(order),
+            _ => {}
         }
     }
 
-    constants.container_size
+    (constants.container_size, content_overflow)
 }
 
 /// Compute constants that can be reused during the flexbox algorithm.
@@ -414,12 +477,13 @@ fn compute_constants(
         width: node_outer_size.width.maybe_sub(padding_border.horizontal_axis_sum()),
         height: node_outer_size.height.maybe_sub(padding_border.vertical_axis_sum()),
     };
-    let gap = style.gap.resolve_or_zero(node_inner_size.or(Size::zero()));
+    // Negative gaps are invalid, so they are clamped to zero
+    let gap = resolve_gap(style.gap, node_inner_size.unwrap_or(Size::zero()));
 
     let container_size = Size::zero();
     let inner_container_size = Size::zero();
 
-    AlgoConstants {
+    let mut constants = AlgoConstants {
         dir,
         is_row,
         is_column,
@@ -432,6 +496,72 @@ fn compute_constants(
         node_inner_size,
         container_size,
         inner_container_size,
+    };
+
+    // Scroll containers always reserve space for a scrollbar in each axis with `overflow: scroll`.
+    // A vertical scrollbar takes up horizontal space and vice versa.
+    let scrollbar_gutter = Size {
+        width: if style.overflow.y == Overflow::Scroll { style.scrollbar_width } else { 0.0 },
+        height: if style.overflow.x == Overflow::Scroll { style.scrollbar_width } else { 0.0 },
+    };
+    if scrollbar_gutter != Size::ZERO {
+        reserve_scrollbar_gutter(&mut constants, scrollbar_gutter);
+    }
+
+    constants
+}
+
+/// Reserves space for scrollbars at the end edges of the container, between the border and the padding.
+///
+/// The `gutter` is the space taken up by scrollbars in each axis: a vertical scrollbar takes up width and a horizontal
+/// scrollbar takes up height. As the gutter behaves exactly like extra padding for the purposes of layout, we treat it as such.
+#[inline]
+fn reserve_scrollbar_gutter(constants: &mut AlgoConstants, gutter: Size<f32>) {
+    constants.padding_border.right += gutter.width;
+    constants.padding_border.bottom += gutter.height;
+    constants.node_inner_size.width = constants.node_inner_size.width.maybe_sub(gutter.width);
+    constants.node_inner_size.height = constants.node_inner_size.height.maybe_sub(gutter.height);
+}
+
+/// Determines whether the flex lines overflow the content box of the container in each axis
+///
+/// Differences of less than half a point are ignored, as they disappear when the layout is rounded.
+#[inline]
+fn determine_content_overflow(flex_lines: &[FlexLine], constants: &AlgoConstants) -> Point<bool> {
+    let main_gap = constants.gap.main(constants.dir);
+    let main_content_size = flex_lines.iter().fold(0.0, |acc: f32, line| {
+        let line_size: f32 = line.items.iter().map(|item| item.outer_target_size.main(constants.dir)).sum();
+        acc.max(line_size + sum_axis_gaps(main_gap, line.items.len()))
+    });
+    // The cross size of a single line may have been set to the container's size, so we also consider the size of its items
+    let cross_content_size = flex_lines.iter().fold(0.0, |acc: f32, line| {
+        let line_size = line.items.iter().fold(line.cross_size, |acc, item| {
+            acc.max(item.target_size.cross(constants.dir) + item.margin.cross_axis_sum(constants.dir))
+        });
+        acc + line_size
+    }) + sum_axis_gaps(constants.gap.cross(constants.dir), flex_lines.len());
+
+    let overflows_main = round(main_content_size) > round(constants.inner_container_size.main(constants.dir));
+    let overflows_cross = round(cross_content_size) > round(constants.inner_container_size.cross(constants.dir));
+
+    if constants.is_row {
+        Point { x: overflows_main, y: overflows_cross }
+    } else {
+        Point { x: overflows_cross, y: overflows_main }
+    }
+}
+
+/// Collects the children of a flex container, along with their order (their index among their siblings).
+///
+/// [`Display::Contents`] nodes do not generate a box, so their children are collected (recursively) after the node
+/// itself as if they were children of the container. The `Contents` nodes themselves are also collected so that their
+/// layout can be set.
+fn collect_children(tree: &impl LayoutTree, node: Node, children: &mut Vec<(u32, Node)>) {
+    for (order, child) in tree.children(node).copied().enumerate() {
+        children.push((order as u32, child));
+        if tree.style(child).display == Display::Contents {
+            collect_children(tree, child, children);
+        }
     }
 }
 
@@ -441,13 +571,19 @@ fn compute_constants(
 ///
 /// - [**Generate anonymous flex items**](https://www.w3.org/TR/css-flexbox-1/#algo-anon-box) as described in [§4 Flex Items](https://www.w3.org/TR/css-flexbox-1/#flex-items).
 #[inline]
-fn generate_anonymous_flex_items(tree: &impl LayoutTree, node: Node, constants: &AlgoConstants) -> Vec<FlexItem> {
-    tree.children(node)
-        .map(|child| (child, tree.style(*child)))
-        .filter(|(_, style)| style.position != Position::Absolute)
-        .filter(|(_, style)| style.display != Display::None)
-        .map(|(child, child_style)| FlexItem {
-            node: *child,
+fn generate_anonymous_flex_items(
+    tree: &impl LayoutTree,
+    children: &[(u32, Node)],
+    constants: &AlgoConstants,
+) -> Vec<FlexItem> {
+    children
+        .iter()
+        .map(|&(order, child)| (order, child, tree.style(child)))
+        .filter(|(_, _, style)| !style.position.is_absolutely_positioned())
+        .filter(|(_, _, style)| style.display != Display::None && style.display != Display::Contents)
+        .map(|(order, child, child_style)| FlexItem {
+            node: child,
+            order,
             size: child_style.size.maybe_resolve(constants.node_inner_size),
             min_size: child_style.min_size.maybe_resolve(constants.node_inner_size),
             max_size: child_style.max_size.maybe_resolve(constants.node_inner_size),
@@ -476,6 +612,35 @@ fn generate_anonymous_flex_items(tree: &impl LayoutTree, node: Node, constants:
         .collect()
 }
 
+/// Records a warning for each percentage that is resolved against a size which depends on the percentage itself.
+///
+/// This only reports the cycles, it does not break them: the percentage sizes of the items have already been resolved to
+/// `None` (i.e. `auto`), and a percentage gap is re-resolved against the content size once the flex lines are known.
+fn record_cyclic_percentage_warnings(
+    tree: &mut impl LayoutTree,
+    node: Node,
+    flex_items: &[FlexItem],
+    constants: &AlgoConstants,
+) {
+    let is_cyclic = |dimension: Dimension, container_size: Option<f32>| {
+        container_size.is_none() && matches!(dimension, Dimension::Percent(_))
+    };
+
+    let main_gap = tree.style(node).gap.main(constants.dir);
+    if constants.node_inner_size.main(constants.dir).is_none() && matches!(main_gap, LengthPercentage::Percent(_)) {
+        tree.record_warning(LayoutWarning::CyclicPercentageGap { node });
+    }
+
+    for item in flex_items {
+        let size = tree.style(item.node).size;
+        if is_cyclic(size.width, constants.node_inner_size.width)
+            || is_cyclic(size.height, constants.node_inner_size.height)
+        {
+            tree.record_warning(LayoutWarning::CyclicPercentageSize { node: item.node });
+        }
+    }
+}
+
 /// Determine the available main and cross space for the flex items.
 ///
 /// # [9.2. Line Length Determination](https://www.w3.org/TR/css-flexbox-1/#line-sizing)
@@ -541,7 +706,6 @@ fn determine_available_space(
 #[inline]
 fn determine_flex_base_size(
     tree: &mut impl LayoutTree,
-    node_size: Size<Option<f32>>,
     constants: &AlgoConstants,
     available_space: Size<AvailableSpace>,
     flex_items: &mut Vec<FlexItem>,
@@ -564,9 +728,10 @@ fn determine_flex_base_size(
         //    cross size and the flex item’s intrinsic aspect ratio.
 
         if let Some(ratio) = child_style.aspect_ratio {
-            if let Some(cross) = node_size.cross(constants.dir) {
+            if let Some(cross) = child.size.cross(constants.dir) {
                 if child_style.flex_basis == Dimension::Auto {
-                    child.flex_basis = cross * ratio;
+                    // The aspect ratio is width divided by height
+                    child.flex_basis = if constants.is_row { cross * ratio } else { cross / ratio };
                     continue;
                 }
             }
@@ -628,25 +793,29 @@ fn determine_flex_base_size(
         child.inner_flex_basis =
             child.flex_basis - child.padding.main_axis_sum(constants.dir) - child.border.main_axis_sum(constants.dir);
 
-        let min_content_size = compute_node_layout(
-            tree,
-            child.node,
-            Size::NONE,
-            constants.node_inner_size,
-            Size::MIN_CONTENT,
-            RunMode::ComputeSize,
-            SizingMode::ContentSize,
-        );
-
         // 4.5. Automatic Minimum Size of Flex Items
         // https://www.w3.org/TR/css-flexbox-1/#min-size-auto
-        let specified = child.size.maybe_min(child.max_size);
-        child.resolved_minimum_size = child.min_size.unwrap_or(min_content_size.maybe_min(specified));
+        // The automatic minimum size of a scroll container is zero, so its content does not need to be measured
+        let overflow = tree.style(child.node).overflow;
+        let is_scroll_container = overflow.x != Overflow::Visible || overflow.y != Overflow::Visible;
+        let automatic_minimum_size = if is_scroll_container {
+            Size::zero()
+        } else {
+            let min_content_size = compute_node_layout(
+                tree,
+                child.node,
+                Size::NONE,
+                constants.node_inner_size,
+                Size::MIN_CONTENT,
+                RunMode::ComputeSize,
+                SizingMode::ContentSize,
+            );
+            let specified = child.size.maybe_min(child.max_size);
+            min_content_size.maybe_min(specified)
+        };
+        child.resolved_minimum_size = child.min_size.unwrap_or(automatic_minimum_size);
 
-        let hypothetical_inner_min_main = min_content_size
-            .main(constants.dir)
-            .maybe_clamp(child.resolved_minimum_size.main(constants.dir).into(), child.size.main(constants.dir))
-            .into();
+        let hypothetical_inner_min_main = Some(child.resolved_minimum_size.main(constants.dir));
         child.hypothetical_inner_size.set_main(
             constants.dir,
             child.flex_basis.maybe_clamp(hypothetical_inner_min_main, child.max_size.main(constants.dir)),
@@ -683,7 +852,7 @@ fn collect_flex_lines<'a>(
     available_space: Size<AvailableSpace>,
     flex_items: &'a mut Vec<FlexItem>,
 ) -> Vec<FlexLine<'a>> {
-    let mut lines = crate::sys::new_vec_with_capacity(1);
+    let mut lines = new_vec_with_capacity(1);
 
     if tree.style(node).flex_wrap == FlexWrap::NoWrap {
         lines.push(FlexLine {
@@ -745,6 +914,8 @@ fn resolve_flexible_lengths(
     let total_hypothetical_outer_main_size =
         line.items.iter().map(|child| child.hypothetical_outer_size.main(constants.dir)).sum::<f32>();
     let used_flex_factor: f32 = total_original_main_axis_gap + total_hypothetical_outer_main_size;
+    // Note: if the inner main size is indefinite (e.g. when sizing under a max-content constraint) then there is no free
+    // space to distribute, so items are never grown beyond their hypothetical main size.
     let growing = used_flex_factor < constants.node_inner_size.main(constants.dir).unwrap_or(0.0);
     let shrinking = !growing;
 
@@ -1002,22 +1173,11 @@ fn determine_hypothetical_cross_size(
 #[inline]
 fn calculate_children_base_lines(
     tree: &mut impl LayoutTree,
-    node: Node,
     node_size: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
     flex_lines: &mut [FlexLine],
     constants: &AlgoConstants,
 ) {
-    /// Recursively calculates the baseline for children
-    fn calc_baseline(db: &impl LayoutTree, node: Node, layout: &Layout) -> f32 {
-        if let Some(first_child) = db.children(node).next() {
-            let layout = db.layout(*first_child);
-            calc_baseline(db, *first_child, layout)
-        } else {
-            layout.size.height
-        }
-    }
-
     for line in flex_lines {
         for child in line.items.iter_mut() {
             let preliminary_size = compute_node_layout(
@@ -1052,13 +1212,14 @@ fn calculate_children_base_lines(
                 SizingMode::ContentSize,
             );
 
-            child.baseline = calc_baseline(
+            child.baseline = compute_baseline(
                 tree,
                 child.node,
                 &Layout {
-                    order: tree.children(node).position(|n| *n == child.node).unwrap() as u32,
+                    order: child.order,
                     size: preliminary_size,
                     location: Point::zero(),
+                    scroll_size: preliminary_size,
                 },
             );
         }
@@ -1116,7 +1277,10 @@ fn calculate_cross_size(
                 .iter()
                 .map(|child| {
                     let child_style = tree.style(child.node);
-                    if child.align_self == AlignSelf::Baseline
+                    // Items in a column container don't have an inline-axis parallel to the main axis,
+                    // so they never participate in baseline alignment (they fall back to flex-start)
+                    if constants.is_row
+                        && child.align_self == AlignSelf::Baseline
                         && child_style.margin.cross_start(constants.dir) != LengthPercentageAuto::Auto
                         && child_style.margin.cross_end(constants.dir) != LengthPercentageAuto::Auto
                         && child_style.size.cross(constants.dir) == Dimension::Auto
@@ -1362,8 +1526,8 @@ fn align_flex_items_along_cross_axis(
             if constants.is_row {
                 max_baseline - child.baseline
             } else {
-                // baseline alignment only makes sense if the constants.direction is row
-                // we treat it as flex-start alignment in columns.
+                // Baseline alignment only applies to items whose inline axis is parallel to the main
+                // axis, so in column containers it falls back to flex-start alignment.
                 if constants.is_wrap_reverse {
                     free_space
                 } else {
@@ -1450,7 +1614,6 @@ fn align_flex_lines_per_align_content(
 #[allow(clippy::too_many_arguments)]
 fn calculate_flex_item(
     tree: &mut impl LayoutTree,
-    node: Node,
     item: &mut FlexItem,
     total_offset_main: &mut f32,
     total_offset_cross: f32,
@@ -1480,15 +1643,14 @@ fn calculate_flex_item(
         + item.margin.cross_start(direction)
         + (item.inset.cross_start(direction).unwrap_or(0.0) - item.inset.cross_end(direction).unwrap_or(0.0));
 
-    let order = tree.children(node).position(|n| *n == item.node).unwrap() as u32;
-
     *tree.layout_mut(item.node) = Layout {
-        order,
+        order: item.order,
         size: preliminary_size,
         location: Point {
             x: if direction.is_row() { offset_main } else { offset_cross },
             y: if direction.is_column() { offset_main } else { offset_cross },
         },
+        scroll_size: preliminary_size,
     };
 
     *total_offset_main += item.offset_main + item.margin.main_axis_sum(direction) + preliminary_size.main(direction);
@@ -1498,7 +1660,6 @@ fn calculate_flex_item(
 #[allow(clippy::too_many_arguments)]
 fn calculate_layout_line(
     tree: &mut impl LayoutTree,
-    node: Node,
     line: &mut FlexLine,
     total_offset_cross: &mut f32,
     container_size: Size<f32>,
@@ -1513,7 +1674,6 @@ fn calculate_layout_line(
         for item in line.items.iter_mut().rev() {
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1527,7 +1687,6 @@ fn calculate_layout_line(
         for item in line.items.iter_mut() {
             calculate_flex_item(
                 tree,
-                node,
                 item,
                 &mut total_offset_main,
                 *total_offset_cross,
@@ -1544,14 +1703,13 @@ fn calculate_layout_line(
 
 /// Do a final layout pass and collect the resulting layouts.
 #[inline]
-fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
+fn final_layout_pass(tree: &mut impl LayoutTree, flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
     let mut total_offset_cross = constants.padding_border.cross_start(constants.dir);
 
     if constants.is_wrap_reverse {
         for line in flex_lines.iter_mut().rev() {
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
@@ -1564,7 +1722,6 @@ fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [F
         for line in flex_lines.iter_mut() {
             calculate_layout_line(
                 tree,
-                node,
                 line,
                 &mut total_offset_cross,
                 constants.container_size,
@@ -1578,21 +1735,21 @@ fn final_layout_pass(tree: &mut impl LayoutTree, node: Node, flex_lines: &mut [F
 
 /// Perform absolute layout on all absolutely positioned children.
 #[inline]
-fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node: Node, constants: &AlgoConstants) {
-    // TODO: remove number of Vec<_> generated
-    let candidates = tree
-        .children(node)
-        .cloned()
-        .enumerate()
-        .filter(|(_, child)| tree.style(*child).position == Position::Absolute)
-        .collect::<Vec<_>>();
-
-    for (order, child) in candidates {
+fn perform_absolute_layout_on_absolute_children(
+    tree: &mut impl LayoutTree,
+    node: Node,
+    children: &[(u32, Node)],
+    constants: &AlgoConstants,
+) {
+    for &(order, child) in children {
+        let child_style = tree.style(child);
+        if !child_style.position.is_absolutely_positioned() || child_style.display == Display::Contents {
+            continue;
+        }
+
         let container_width = constants.container_size.width;
         let container_height = constants.container_size.height;
 
-        let child_style = tree.style(child);
-
         // X-axis
         let child_position_start = child_style.inset.left.maybe_resolve(container_width);
         let child_margin_start = child_style.margin.left.maybe_resolve(container_width);
@@ -1682,6 +1839,7 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         } else if end_main.is_some() {
             free_main_space - end_main.unwrap_or(0.0) - constants.border.main_end(constants.dir)
         } else {
+            // Both insets are auto, so the item is placed at its static position
             match tree.style(node).justify_content.unwrap_or(JustifyContent::Start) {
                 // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
                 // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
@@ -1700,6 +1858,7 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         } else if end_cross.is_some() {
             free_cross_space - end_cross.unwrap_or(0.0) - constants.border.cross_end(constants.dir)
         } else {
+            // Both insets are auto, so the item is placed at its static position
             match child_style.align_self.unwrap_or(constants.align_items) {
                 AlignSelf::Start => {
                     if constants.is_wrap_reverse {
@@ -1728,12 +1887,13 @@ fn perform_absolute_layout_on_absolute_children(tree: &mut impl LayoutTree, node
         };
 
         *tree.layout_mut(child) = Layout {
-            order: order as u32,
+            order,
             size: preliminary_size,
             location: Point {
                 x: if constants.is_row { offset_main } else { offset_cross },
                 y: if constants.is_column { offset_main } else { offset_cross },
             },
+            scroll_size: preliminary_size,
         };
     }
 }
@@ -1,7 +1,7 @@
 //! Alignment of tracks and final positioning of items
 use super::types::GridTrack;
 use crate::axis::InBothAbsAxis;
-use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::alignment::{compute_alignment_offset, compute_baseline};
 use crate::compute::compute_node_layout;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::layout::{Layout, RunMode, SizingMode};
@@ -27,6 +27,21 @@ pub(super) fn align_tracks(
     let free_space = f32_max(size_diff, 0.0);
     let overflow = f32_min(size_diff, 0.0);
 
+    // Count the number of non-collapsed tracks (not counting gutters)
+    let num_tracks = tracks.iter().skip(1).step_by(2).filter(|track| !track.is_collapsed).count();
+
+    // The distributed alignment modes fall back to a positional alignment when there is only a single track
+    // to distribute space around. See: https://www.w3.org/TR/css-align-3/#distribution-values
+    let track_alignment_style = if num_tracks <= 1 {
+        match track_alignment_style {
+            AlignContent::SpaceBetween => AlignContent::Start,
+            AlignContent::SpaceAround | AlignContent::SpaceEvenly => AlignContent::Center,
+            _ => track_alignment_style,
+        }
+    } else {
+        track_alignment_style
+    };
+
     // If the used_size > grid_container_size then the tracks must overflow their container
     // The direction in which they do so is determined by the alignment style
     let origin = padding.start
@@ -41,9 +56,6 @@ pub(super) fn align_tracks(
             AlignContent::SpaceAround => 0.0,
         };
 
-    // Count the number of non-collapsed tracks (not counting gutters)
-    let num_tracks = tracks.iter().skip(1).step_by(2).filter(|track| !track.is_collapsed).count();
-
     // Grid layout treats gaps as full tracks rather than applying them at alignment so we
     // simply pass zero here. Grid layout is never reversed.
     let gap = 0.0;
@@ -70,13 +82,16 @@ pub(super) fn align_tracks(
 }
 
 /// Align and size a grid item into it's final position
+///
+/// Items which are baseline-aligned in the block axis are positioned as if start-aligned, and their baseline (relative to
+/// the top of their grid area) is returned so that the caller can align them with the other items in their row.
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutTree,
     node: Node,
     order: u32,
     grid_area: Rect<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-) {
+) -> Option<f32> {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
     let style = tree.style(node);
@@ -91,16 +106,17 @@ pub(super) fn align_and_position_item(
     let min_size = style.min_size.maybe_resolve(grid_area_size);
     let max_size = style.max_size.maybe_resolve(grid_area_size);
 
-    // Resolve default alignment styles if they are set on neither the parent or the node itself
+    // Resolve alignment styles. The node's own `justify_self`/`align_self` take precedence over the container's
+    // `justify_items`/`align_items`, and a default is used if they are set on neither the parent or the node itself.
     let alignment_styles = InBothAbsAxis {
-        horizontal: container_alignment_styles.horizontal.or(justify_self).unwrap_or_else(|| {
+        horizontal: justify_self.or(container_alignment_styles.horizontal).unwrap_or_else(|| {
             if inherent_size.width.is_some() || aspect_ratio.is_some() {
                 AlignSelf::Start
             } else {
                 AlignSelf::Stretch
             }
         }),
-        vertical: container_alignment_styles.vertical.or(align_self).unwrap_or_else(|| {
+        vertical: align_self.or(container_alignment_styles.vertical).unwrap_or_else(|| {
             if inherent_size.height.is_some() || aspect_ratio.is_some() {
                 AlignSelf::Start
             } else {
@@ -122,7 +138,7 @@ pub(super) fn align_and_position_item(
     let width = inherent_size.width.or_else(|| {
         // Apply width derived from both the left and right properties of an absolutely
         // positioned element being set
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(left), Some(right)) = (inset_horizontal.start, inset_horizontal.end) {
                 return Some(f32_max(grid_area_size.width - left - right, 0.0));
             }
@@ -135,7 +151,7 @@ pub(super) fn align_and_position_item(
         if margin.left.is_some()
             && margin.right.is_some()
             && alignment_styles.horizontal == AlignSelf::Stretch
-            && position != Position::Absolute
+            && !position.is_absolutely_positioned()
         {
             return Some(grid_area_minus_item_margins_size.width.maybe_min(max_size.width).maybe_max(min_size.width));
         }
@@ -143,7 +159,7 @@ pub(super) fn align_and_position_item(
         None
     });
     let height = inherent_size.height.or_else(|| {
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(top), Some(bottom)) = (inset_vertical.start, inset_vertical.end) {
                 return Some(f32_max(grid_area_size.height - top - bottom, 0.0));
             }
@@ -156,7 +172,7 @@ pub(super) fn align_and_position_item(
         if margin.top.is_some()
             && margin.bottom.is_some()
             && alignment_styles.vertical == AlignSelf::Stretch
-            && position != Position::Absolute
+            && !position.is_absolutely_positioned()
         {
             return Some(
                 grid_area_minus_item_margins_size.height.maybe_min(max_size.height).maybe_max(min_size.height),
@@ -179,7 +195,7 @@ pub(super) fn align_and_position_item(
 
     let (x, width) = align_and_size_item_within_area(
         Line { start: grid_area.left, end: grid_area.right },
-        justify_self.unwrap_or(alignment_styles.horizontal),
+        alignment_styles.horizontal,
         width,
         measured_size.width,
         position,
@@ -188,7 +204,7 @@ pub(super) fn align_and_position_item(
     );
     let (y, height) = align_and_size_item_within_area(
         Line { start: grid_area.top, end: grid_area.bottom },
-        align_self.unwrap_or(alignment_styles.vertical),
+        alignment_styles.vertical,
         height,
         measured_size.height,
         position,
@@ -196,7 +212,15 @@ pub(super) fn align_and_position_item(
         margin.vertical_components(),
     );
 
-    *tree.layout_mut(node) = Layout { order, size: Size { width, height }, location: Point { x, y } };
+    let size = Size { width, height };
+    let layout = Layout { order, size, location: Point { x, y }, scroll_size: size };
+    *tree.layout_mut(node) = layout;
+
+    if alignment_styles.vertical == AlignSelf::Baseline && !position.is_absolutely_positioned() {
+        Some(y - grid_area.top + compute_baseline(tree, node, &layout))
+    } else {
+        None
+    }
 }
 
 /// Align and size a grid item along a single axis
@@ -222,7 +246,7 @@ pub(super) fn align_and_size_item_within_area(
 
     // Compute size in the axis
     let size = style_size.unwrap_or_else(|| {
-        if alignment_style == AlignItems::Stretch && position != Position::Absolute {
+        if alignment_style == AlignItems::Stretch && !position.is_absolutely_positioned() {
             f32_max(grid_area_size - resolved_margin.sum(), measured_size)
         } else {
             measured_size
@@ -234,12 +258,13 @@ pub(super) fn align_and_size_item_within_area(
         AlignSelf::Start => resolved_margin.start,
         AlignSelf::End => grid_area_size - size - resolved_margin.end,
         AlignSelf::Center => (grid_area_size - size + resolved_margin.start - resolved_margin.end) / 2.0,
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
+        // Baseline-aligned items start out start-aligned. In the block axis they are then shifted to share a baseline with
+        // the other items in their row by the caller, and in the inline axis baseline alignment is not supported.
         AlignSelf::Baseline => resolved_margin.start,
         AlignSelf::Stretch => resolved_margin.start,
     };
 
-    let offset_within_area = if position == Position::Absolute {
+    let offset_within_area = if position.is_absolutely_positioned() {
         if let Some(start) = inset.start {
             start + non_auto_margin.start
         } else if let Some(end) = inset.end {
@@ -8,8 +8,7 @@ use crate::style::{
     AvailableSpace, GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, Style, TrackSizingFunction,
 };
 use crate::style_helpers::TaffyAuto;
-use crate::sys::{GridTrackVec, Vec};
-use core::cmp::{max, min};
+use crate::sys::Vec;
 
 /// Compute the number of rows and columns in the explicit grid
 pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAxis) -> u16 {
@@ -105,7 +104,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
                     TrackSizingFunction::AutoRepeat(_, _) => 0.0,
                 })
                 .sum();
-            let gap_size = style.gap.get_abs(axis).resolve_or_zero(Some(inner_container_size));
+            let gap_size = style.gap.get_abs(axis).resolve_or_zero(Some(inner_container_size)).max(0.0);
 
             // Compute the amount of space that a single repetition of the repeated track list takes
             let per_repetition_track_used_space: f32 = repetition_definition
@@ -153,7 +152,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(style: &Style, axis: AbsoluteAx
 pub(super) fn initialize_grid_tracks(
     tracks: &mut Vec<GridTrack>,
     counts: TrackCounts,
-    track_template: &GridTrackVec<TrackSizingFunction>,
+    track_template: &[TrackSizingFunction],
     auto_tracks: &Vec<NonRepeatedTrackSizingFunction>,
     gap: LengthPercentage,
     track_has_items: impl Fn(usize) -> bool,
@@ -169,9 +168,9 @@ pub(super) fn initialize_grid_tracks(
         let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
         create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
     } else {
-        let max_count = max(auto_tracks.len(), counts.negative_implicit as usize);
-        let min_count = min(auto_tracks.len(), counts.negative_implicit as usize);
-        let offset = max_count % min_count;
+        // Negative implicit tracks cycle backwards through the auto tracks from the start of the explicit grid, so the
+        // track immediately before the explicit grid uses the last auto track
+        let offset = (auto_tracks.len() - (counts.negative_implicit as usize % auto_tracks.len())) % auto_tracks.len();
         let iter = auto_tracks.iter().copied().cycle().skip(offset);
         create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
     }
@@ -264,7 +263,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             size: Size { width: points(120.0), height: points(80.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             ..Default::default()
         };
@@ -280,7 +279,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             size: Size { width: points(140.0), height: points(90.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             ..Default::default()
         };
@@ -296,7 +295,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             min_size: Size { width: points(120.0), height: points(80.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             ..Default::default()
         };
@@ -312,7 +311,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             min_size: Size { width: points(140.0), height: points(90.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             ..Default::default()
         };
@@ -328,7 +327,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             size: Size { width: points(140.0), height: points(100.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0), points(20.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0), points(20.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0), points(10.0)])],
             ..Default::default()
         };
@@ -344,7 +343,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             size: Size { width: points(140.0), height: points(100.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             gap: points(20.0),
             ..Default::default()
@@ -360,7 +359,10 @@ mod test {
         use GridTrackRepetition::AutoFill;
         let grid_style = Style {
             display: Display::Grid,
-            grid_template_columns: vec![repeat(AutoFill, vec![points(40.0), percent(0.5), points(20.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(
+                AutoFill,
+                vec![points(40.0), percent(0.5), points(20.0)],
+            )]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             gap: points(20.0),
             ..Default::default()
@@ -377,7 +379,7 @@ mod test {
         let grid_style = Style {
             display: Display::Grid,
             size: Size { width: points(140.0), height: points(100.0) },
-            grid_template_columns: vec![points(20.0), repeat(AutoFill, vec![points(40.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![points(20.0), repeat(AutoFill, vec![points(40.0)])]),
             grid_template_rows: vec![points(40.0), repeat(AutoFill, vec![points(20.0)])],
             gap: points(20.0),
             ..Default::default()
@@ -395,7 +397,7 @@ mod test {
             display: Display::Grid,
             size: Size { width: points(120.0), height: points(120.0) },
             padding: Rect { left: points(10.0), right: points(10.0), top: points(20.0), bottom: points(20.0) },
-            grid_template_columns: vec![repeat(AutoFill, vec![points(20.0)])],
+            grid_template_columns: GridTemplate::Tracks(vec![repeat(AutoFill, vec![points(20.0)])]),
             grid_template_rows: vec![repeat(AutoFill, vec![points(20.0)])],
             ..Default::default()
         };
@@ -2,11 +2,11 @@
 //! https://www.w3.org/TR/css-grid-1/
 use crate::axis::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Rect, Size};
-use crate::layout::{Layout, RunMode, SizingMode};
+use crate::layout::{Layout, RunMode, SizingMode, SubgridColumns};
 use crate::math::MaybeMath;
 use crate::node::Node;
 use crate::resolve::{MaybeResolve, ResolveOrZero};
-use crate::style::{AlignContent, AvailableSpace, Display, Position};
+use crate::style::{AlignContent, AvailableSpace, Display, GridTemplate, LengthPercentage};
 use crate::style_helpers::*;
 use crate::sys::{GridTrackVec, Vec};
 use crate::tree::LayoutTree;
@@ -14,8 +14,12 @@ use alignment::{align_and_position_item, align_tracks};
 use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
 use placement::place_grid_items;
+use subgrid::{
+    apply_subgrid_gutters, record_subgrid_column_edges, record_subgrid_column_sizing_functions,
+    resolve_subgrid_columns, subgrid_tracks_from_edges,
+};
 use track_sizing::{determine_if_item_crosses_flexible_tracks, resolve_item_track_indexes, track_sizing_algorithm};
-use types::{CellOccupancyMatrix, GridTrack};
+use types::{CellOccupancyMatrix, GridTrack, GridTrackKind};
 
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
 
@@ -28,6 +32,7 @@ mod alignment;
 mod explicit_grid;
 mod implicit_grid;
 mod placement;
+mod subgrid;
 mod track_sizing;
 mod types;
 mod util;
@@ -44,9 +49,30 @@ pub fn compute(
     known_dimensions: Size<Option<f32>>,
     parent_size: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
 ) -> Size<f32> {
     let get_child_styles_iter = |node| tree.children(node).map(|child_node: &Node| tree.style(*child_node));
-    let style = tree.style(node).clone();
+    let mut style = tree.style(node).clone();
+
+    // 0. Resolve subgrid columns
+    // A subgrid adopts the columns of the parent grid that it spans, as recorded by the parent. While the parent's tracks
+    // are being sized these are the parent's sizing functions, and once they have been sized they are the parent's
+    // resolved column edges. If the node is not a grid item then it behaves as if no columns had been specified.
+    let mut subgrid_column_edges = None;
+    if style.grid_template_columns.is_subgrid() {
+        match resolve_subgrid_columns(tree, node) {
+            Some(SubgridColumns::SizingFunctions(tracks, column_gap)) => {
+                style.grid_template_columns = GridTemplate::Tracks(tracks);
+                style.gap.width = column_gap;
+            }
+            Some(SubgridColumns::Edges(edges)) => {
+                style.grid_template_columns = GridTemplate::Tracks(subgrid_tracks_from_edges(&edges));
+                style.gap.width = LengthPercentage::Points(0.0);
+                subgrid_column_edges = Some(edges);
+            }
+            None => {}
+        }
+    }
     let child_styles_iter = get_child_styles_iter(node);
 
     // 1. Resolve the explicit grid
@@ -65,12 +91,15 @@ pub fn compute(
     let mut items = Vec::with_capacity(tree.child_count(node));
     let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
     let grid_auto_flow = style.grid_auto_flow;
+    // Children are placed (and painted) in "order-modified document order": sorted by their `order` style, with
+    // children of equal `order` kept in document order.
+    let mut ordered_children: Vec<(usize, Node)> = tree.children(node).copied().enumerate().collect();
+    ordered_children.sort_by_key(|(_, child_node)| tree.style(*child_node).order);
     let in_flow_children_iter = || {
-        tree.children(node)
-            .copied()
-            .enumerate()
-            .map(|(index, child_node)| (index, child_node, tree.style(child_node)))
-            .filter(|(_, _, style)| style.display != Display::None && style.position != Position::Absolute)
+        ordered_children
+            .iter()
+            .map(|&(index, child_node)| (index, child_node, tree.style(child_node)))
+            .filter(|(_, _, style)| style.display != Display::None && !style.position.is_absolutely_positioned())
     };
     place_grid_items(&mut cell_occupancy_matrix, &mut items, in_flow_children_iter, grid_auto_flow);
 
@@ -86,11 +115,14 @@ pub fn compute(
     initialize_grid_tracks(
         &mut columns,
         final_col_counts,
-        &style.grid_template_columns,
+        style.grid_template_columns.tracks(),
         &style.grid_auto_columns,
         style.gap.width,
         |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
     );
+    if let Some(edges) = subgrid_column_edges {
+        apply_subgrid_gutters(&mut columns, final_col_counts, &edges);
+    }
     initialize_grid_tracks(
         &mut rows,
         final_row_counts,
@@ -147,6 +179,9 @@ pub fn compute(
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_tracks(&mut items, &columns, &rows);
 
+    // Provide the sizing functions of the columns that each subgrid item spans, so that it can estimate its size
+    record_subgrid_column_sizing_functions(tree, &items, &columns, style.gap.width);
+
     // Run track sizing algorithm for Inline axis
     track_sizing_algorithm(
         tree,
@@ -205,17 +240,20 @@ pub fn compute(
     );
 
     // 6. Compute container size
-    let resolved_style_size = known_dimensions.or(style.size.maybe_resolve(parent_size));
+    // Sizes that are not imposed by the parent (via known_dimensions) are clamped by the node's min and max sizes
+    let resolved_style_size = known_dimensions.or(size.maybe_clamp(min_size, max_size));
     let container_border_box = Size {
         width: resolved_style_size.get(AbstractAxis::Inline).unwrap_or_else(|| {
-            columns.iter().map(|track| track.base_size).sum::<f32>()
+            (columns.iter().map(|track| track.base_size).sum::<f32>()
                 + padding.horizontal_axis_sum()
-                + border.horizontal_axis_sum()
+                + border.horizontal_axis_sum())
+            .maybe_clamp(min_size.width, max_size.width)
         }),
         height: resolved_style_size.get(AbstractAxis::Block).unwrap_or_else(|| {
-            rows.iter().map(|track| track.base_size).sum::<f32>()
+            (rows.iter().map(|track| track.base_size).sum::<f32>()
                 + padding.vertical_axis_sum()
-                + border.vertical_axis_sum()
+                + border.vertical_axis_sum())
+            .maybe_clamp(min_size.height, max_size.height)
         }),
     };
     let container_content_box = Size {
@@ -223,6 +261,12 @@ pub fn compute(
         height: container_border_box.height - padding.vertical_axis_sum() - border.vertical_axis_sum(),
     };
 
+    // We have the container size.
+    // If our caller does not care about performing layout we are done now.
+    if run_mode == RunMode::ComputeSize {
+        return container_border_box;
+    }
+
     // 7. Track Alignment
 
     // Align columns
@@ -242,6 +286,24 @@ pub fn compute(
         style.align_content.unwrap_or(AlignContent::Stretch),
     );
 
+    // Record the edges of each track relative to the content box
+    let track_edges = |tracks: &[GridTrack], content_box_start: f32| -> Vec<f32> {
+        tracks
+            .iter()
+            .filter(|track| track.kind == GridTrackKind::Track)
+            .flat_map(|track| {
+                let start = track.offset - content_box_start;
+                [start, start + track.base_size]
+            })
+            .collect()
+    };
+    let column_edges = track_edges(&columns, padding.left + border.left);
+    let row_edges = track_edges(&rows, padding.top + border.top);
+    tree.record_grid_lines(node, column_edges, row_edges);
+
+    // Provide the sized columns that each subgrid item spans, so that its column lines match ours
+    record_subgrid_column_edges(tree, &items, &columns);
+
     // 8. Size, Align, and Position Grid Items
 
     // Sort items back into original order to allow them to be matched up with styles
@@ -249,26 +311,48 @@ pub fn compute(
 
     let container_alignment_styles = InBothAbsAxis { horizontal: style.justify_items, vertical: style.align_items };
 
+    // The order of each child is its position in order-modified document order, so that it reflects paint order
+    let mut paint_order: Vec<u32> = core::iter::repeat_n(0, ordered_children.len()).collect();
+    for (position, (index, _)) in ordered_children.iter().enumerate() {
+        paint_order[*index] = position as u32;
+    }
+
     // Position in-flow children (stored in items vector)
-    for (index, item) in items.iter().enumerate() {
+    let mut baselines: Vec<Option<f32>> = Vec::with_capacity(items.len());
+    let mut row_baselines: Vec<f32> = core::iter::repeat_n(0.0, rows.len()).collect();
+    for item in items.iter() {
         let grid_area = Rect {
             top: rows[item.row_indexes.start as usize + 1].offset,
             bottom: rows[item.row_indexes.end as usize].offset,
             left: columns[item.column_indexes.start as usize + 1].offset,
             right: columns[item.column_indexes.end as usize].offset,
         };
-        align_and_position_item(tree, item.node, index as u32, grid_area, container_alignment_styles);
+        let order = paint_order[item.source_order as usize];
+        let baseline = align_and_position_item(tree, item.node, order, grid_area, container_alignment_styles);
+        if let Some(baseline) = baseline {
+            let row_baseline = &mut row_baselines[item.row_indexes.start as usize];
+            *row_baseline = row_baseline.max(baseline);
+        }
+        baselines.push(baseline);
+    }
+
+    // Baseline-aligned items that start in the same row share a baseline, so shift each of them down to line up with the
+    // lowest baseline in their row
+    for (item, baseline) in items.iter().zip(baselines) {
+        if let Some(baseline) = baseline {
+            tree.layout_mut(item.node).location.y += row_baselines[item.row_indexes.start as usize] - baseline;
+        }
     }
 
     // Position hidden and absolutely positioned children
-    let mut order = items.len() as u32;
     (0..tree.child_count(node)).for_each(|index| {
+        let order = paint_order[index];
         let child = tree.child(node, index);
         let child_style = tree.style(child);
 
         // Position hidden child
         if child_style.display == Display::None {
-            *tree.layout_mut(node) = Layout::with_order(order);
+            *tree.layout_mut(child) = Layout::with_order(order);
             compute_node_layout(
                 tree,
                 child,
@@ -278,12 +362,11 @@ pub fn compute(
                 RunMode::PeformLayout,
                 SizingMode::InherentSize,
             );
-            order += 1;
             return;
         }
 
         // Position absolutely positioned child
-        if child_style.position == Position::Absolute {
+        if child_style.position.is_absolutely_positioned() {
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
             // The Option is None if the style property is Auto and an unresolvable Span
             let maybe_col_indexes = child_style
@@ -310,7 +393,6 @@ pub fn compute(
                 right: maybe_col_indexes.end.map(|index| columns[index].offset).unwrap_or(container_border_box.width),
             };
             align_and_position_item(tree, child, order, grid_area, container_alignment_styles);
-            order += 1;
         }
     });
 
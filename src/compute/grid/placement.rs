@@ -213,9 +213,12 @@ fn place_indefinitely_positioned_item(
     let primary_placement_style = placement.get(primary_axis);
     let secondary_placement_style = placement.get(primary_axis.other_axis());
 
-    let primary_span = primary_placement_style.indefinite_span();
-    let secondary_span = secondary_placement_style.indefinite_span();
     let has_definite_primary_axis_position = primary_placement_style.is_definite();
+    let primary_span = match has_definite_primary_axis_position {
+        true => primary_placement_style.resolve_definite_grid_lines().span(),
+        false => primary_placement_style.indefinite_span(),
+    };
+    let secondary_span = secondary_placement_style.indefinite_span();
     let primary_axis_grid_start_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_start_line();
     let primary_axis_grid_end_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_end_line();
     let secondary_axis_grid_start_line =
@@ -382,7 +385,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (line(1), auto(), line(1), auto()).into_grid_child(), (0, 1, 0, 1)),
@@ -402,7 +405,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (line(-1), line(-1), line(-1), line(-1)).into_grid_child(), (2, 3, 2, 3)),
@@ -422,7 +425,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 let auto_child = (auto(), auto(), auto(), auto()).into_grid_child();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
@@ -447,7 +450,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 let auto_child = (auto(), auto(), auto(), auto()).into_grid_child();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
@@ -472,7 +475,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (span(5), auto(), auto(), auto()).into_grid_child(), (0, 5, 0, 1)),
@@ -489,7 +492,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (span(2), auto(), line(1), auto()).into_grid_child(), (0, 2, 0, 1)),
@@ -509,7 +512,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (2, sm.insert(()), (auto(), auto(), line(2), auto()).into_grid_child(), (0, 1, 1, 2)),
@@ -528,7 +531,7 @@ mod tests {
             let explicit_col_count = 4;
             let explicit_row_count = 4;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (line(2), auto(), line(1), auto()).into_grid_child(), (1, 2, 0, 1)), // Definitely positioned in column 2
@@ -547,7 +550,7 @@ mod tests {
             let explicit_col_count = 4;
             let explicit_row_count = 4;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (auto(), span(3), auto(), auto()).into_grid_child(), (0, 3, 0, 1)), // Width 3
@@ -566,7 +569,7 @@ mod tests {
             let explicit_col_count = 2;
             let explicit_row_count = 2;
             let children = {
-                let mut sm = SlotMap::new();
+                let mut sm = SlotMap::with_key();
                 vec![
                     // output order, node, style (grid coords), expected_placement (oz coords)
                     (1, sm.insert(()), (line(-5), auto(), line(1), auto()).into_grid_child(), (-2, -1, 0, 1)), // Row 1. Definitely positioned in column -2
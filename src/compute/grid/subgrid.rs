@@ -0,0 +1,102 @@
+//! Implements a partial version of [subgrid](https://www.w3.org/TR/css-grid-2/#subgrids) for the column axis.
+//!
+//! A grid records the columns that each of its subgrid items spans (see [`SubgridColumns`]): first their sizing functions
+//! while its own tracks are being sized, and then their resolved edges before the subgrid is given its final layout. The
+//! subgrid adopts the recorded columns as its explicit columns, so that its column lines line up with those of the
+//! parent. The subgrid's items do not contribute to the sizing of the parent's tracks, and the subgrid's own padding and
+//! border are not taken into account.
+use super::types::{GridItem, GridTrack, GridTrackKind, TrackCounts};
+use crate::geometry::MinMax;
+use crate::layout::SubgridColumns;
+use crate::node::Node;
+use crate::style::{Display, LengthPercentage, TrackSizingFunction};
+use crate::style_helpers::points;
+use crate::sys::{GridTrackVec, Vec};
+use crate::tree::LayoutTree;
+
+/// Returns the columns recorded for a subgrid by its parent grid, or `None` if the node is not an in-flow child of a grid
+/// (in which case it should behave as if it had no explicit columns)
+pub(super) fn resolve_subgrid_columns(tree: &impl LayoutTree, node: Node) -> Option<SubgridColumns> {
+    let parent = tree.parent(node)?;
+    let style = tree.style(node);
+    if tree.style(parent).display != Display::Grid || style.position.is_absolutely_positioned() {
+        return None;
+    }
+    tree.subgrid_columns(node).cloned()
+}
+
+/// Converts the edges of the parent's columns that a subgrid spans into fixed-size tracks
+pub(super) fn subgrid_tracks_from_edges(edges: &[f32]) -> GridTrackVec<TrackSizingFunction> {
+    edges.chunks_exact(2).map(|track| points(track[1] - track[0])).collect()
+}
+
+/// Sizes the gutters between the explicit columns of a subgrid to match those between the parent's columns that it spans
+pub(super) fn apply_subgrid_gutters(columns: &mut [GridTrack], counts: TrackCounts, edges: &[f32]) {
+    // The gutter following explicit track `i` comes after the leading gutter and the tracks (and gutters) before it
+    let first_gutter_index = 2 * counts.negative_implicit as usize + 2;
+    let gutter_sizes = edges[1..].chunks_exact(2).map(|gutter| gutter[1] - gutter[0]);
+    for (index, size) in gutter_sizes.enumerate() {
+        columns[first_gutter_index + 2 * index] = GridTrack::gutter(LengthPercentage::Points(size));
+    }
+}
+
+/// Records the sizing functions of the columns that each subgrid item spans, along with the column gap
+pub(super) fn record_subgrid_column_sizing_functions(
+    tree: &mut impl LayoutTree,
+    items: &[GridItem],
+    columns: &[GridTrack],
+    gap: LengthPercentage,
+) {
+    for item in items.iter() {
+        if !is_subgrid(tree, item.node) {
+            continue;
+        }
+        let tracks = spanned_columns(item, columns)
+            .iter()
+            .filter(|track| track.kind == GridTrackKind::Track)
+            .map(|track| {
+                TrackSizingFunction::Single(MinMax {
+                    min: track.min_track_sizing_function,
+                    max: track.max_track_sizing_function,
+                })
+            })
+            .collect();
+        record(tree, item.node, SubgridColumns::SizingFunctions(tracks, gap));
+    }
+}
+
+/// Records the edges of the sized and aligned columns that each subgrid item spans
+pub(super) fn record_subgrid_column_edges(tree: &mut impl LayoutTree, items: &[GridItem], columns: &[GridTrack]) {
+    for item in items.iter() {
+        if !is_subgrid(tree, item.node) {
+            continue;
+        }
+        let spanned_columns = spanned_columns(item, columns);
+        let origin = spanned_columns.first().map(|track| track.offset).unwrap_or(0.0);
+        let edges: Vec<f32> = spanned_columns
+            .iter()
+            .filter(|track| track.kind == GridTrackKind::Track)
+            .flat_map(|track| [track.offset - origin, track.offset - origin + track.base_size])
+            .collect();
+        record(tree, item.node, SubgridColumns::Edges(edges));
+    }
+}
+
+/// Whether the node is a grid container whose columns are a subgrid
+fn is_subgrid(tree: &impl LayoutTree, node: Node) -> bool {
+    let style = tree.style(node);
+    style.display == Display::Grid && style.grid_template_columns.is_subgrid()
+}
+
+/// The columns (and the gutters between them) that an item spans
+fn spanned_columns<'a>(item: &GridItem, columns: &'a [GridTrack]) -> &'a [GridTrack] {
+    &columns[item.column_indexes.start as usize + 1..item.column_indexes.end as usize]
+}
+
+/// Records the columns for a subgrid, and clears its cached sizes as they may have been computed with different columns
+fn record(tree: &mut impl LayoutTree, node: Node, columns: SubgridColumns) {
+    tree.record_subgrid_columns(node, columns);
+    for index in 0..tree.cache_size() {
+        *tree.cache_mut(node, index) = None;
+    }
+}
@@ -7,7 +7,7 @@ use crate::math::MaybeMath;
 use crate::prelude::LayoutTree;
 use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style::{AlignContent, AvailableSpace, MaxTrackSizingFunction, MinTrackSizingFunction, Style};
-use crate::sys::{f32_max, f32_min};
+use crate::sys::{f32_max, f32_min, Vec};
 use core::cmp::Ordering;
 
 /// Takes an axis, and a list of grid items sorted firstly by whether they cross a flex track
@@ -511,8 +511,12 @@ fn resolve_intrinsic_track_sizes(
             // 6. For max-content maximums: Lastly continue to increase the growth limit of tracks with a max track sizing function of max-content
             // by distributing extra space as needed to account for these items' max-content contributions. However, limit the growth of any
             // fit-content() tracks by their fit-content() argument.
-            let has_max_content_max_track_sizing_function =
-                move |track: &GridTrack| track.max_track_sizing_function == MaxTrackSizingFunction::MaxContent;
+            let has_max_content_max_track_sizing_function = move |track: &GridTrack| {
+                matches!(
+                    track.max_track_sizing_function,
+                    MaxTrackSizingFunction::MaxContent | MaxTrackSizingFunction::FitContent(_)
+                )
+            };
             for item in batch.iter_mut() {
                 let (_, _, axis_max_content_size) = compute_item_sizes(item, axis_tracks);
                 let space = axis_max_content_size;
@@ -521,6 +525,13 @@ fn resolve_intrinsic_track_sizes(
                     distribute_item_space_to_growth_limit(space, tracks, has_max_content_max_track_sizing_function);
                 }
             }
+            // Growth limits that are still below the fit-content() argument may only grow up to that argument
+            for track in axis_tracks.iter_mut() {
+                let growth_limit =
+                    if track.growth_limit == f32::INFINITY { track.base_size } else { track.growth_limit };
+                let remaining_growth = f32_max(0.0, track.fit_content_limit() - growth_limit);
+                track.growth_limit_planned_increase = f32_min(track.growth_limit_planned_increase, remaining_growth);
+            }
             // Mark any tracks whose growth limit changed from infinite to finite in this step as infinitely growable for the next step.
             flush_planned_growth_limit_increases(axis_tracks, false);
         }
@@ -843,6 +854,7 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
 
 /// 11.8. Stretch auto Tracks
 /// This step expands tracks that have an auto max track sizing function by dividing any remaining positive, definite free space equally amongst them.
+/// It only applies if the content alignment of the grid container in this axis is normal (unset) or `stretch`.
 fn stretch_auto_tracks(
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
@@ -850,6 +862,14 @@ fn stretch_auto_tracks(
     available_space: Size<AvailableSpace>,
     available_grid_space: Size<AvailableSpace>,
 ) {
+    let content_alignment = match axis {
+        AbstractAxis::Inline => container_style.justify_content,
+        AbstractAxis::Block => container_style.align_content,
+    };
+    if !matches!(content_alignment, None | Some(AlignContent::Stretch)) {
+        return;
+    }
+
     let num_auto_tracks =
         axis_tracks.iter().filter(|track| track.max_track_sizing_function == MaxTrackSizingFunction::Auto).count();
     if num_auto_tracks > 0 {
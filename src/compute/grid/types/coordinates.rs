@@ -12,7 +12,8 @@ use core::ops::{Add, AddAssign, Sub};
 ///   - The line at the right hand (or bottom) edge of the explicit grid is -1
 ///     (and counts down from there)
 ///   - 0 is not a valid index
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(transparent)]
 pub struct GridLine(i16);
 
@@ -89,7 +89,13 @@ impl GridTrack {
     }
 
     /// Create a new GridTrack representing a gutter
+    ///
+    /// Negative gaps are invalid, so they are clamped to zero
     pub fn gutter(size: LengthPercentage) -> GridTrack {
+        let size = match size {
+            LengthPercentage::Points(points) => LengthPercentage::Points(points.max(0.0)),
+            LengthPercentage::Percent(fraction) => LengthPercentage::Percent(fraction.max(0.0)),
+        };
         Self::new_with_kind(
             GridTrackKind::Gutter,
             MinTrackSizingFunction::Fixed(size),
@@ -11,7 +11,7 @@ impl CreateParentTestNode for (f32, f32, i32, i32) {
         Style {
             display: Display::Grid,
             size: Size { width: Dimension::Points(self.0), height: Dimension::Points(self.1) },
-            grid_template_columns: vec![flex(1f32); self.2 as usize],
+            grid_template_columns: GridTemplate::Tracks(vec![flex(1f32); self.2 as usize]),
             grid_template_rows: vec![flex(1f32); self.3 as usize],
             ..Default::default()
         }
@@ -25,18 +25,23 @@ pub(crate) fn compute(
 
     // Resolve node's preferred/min/max sizes (width/heights) against the available space (percentages resolve to pixel values)
     // For ContentSize mode, we pretend that the node has no size styles as these should be ignored.
+    // In both modes, a missing dimension is transferred from the other through the node's aspect ratio (if any).
     let (node_size, node_min_size, node_max_size) = match sizing_mode {
         SizingMode::ContentSize => {
-            let node_size = known_dimensions;
+            let node_size = known_dimensions.maybe_apply_aspect_ratio(style.aspect_ratio, Size::NONE, Size::NONE);
             let node_min_size = Size::NONE;
             let node_max_size = Size::NONE;
             (node_size, node_min_size, node_max_size)
         }
         SizingMode::InherentSize => {
             let style_size = style.size.maybe_resolve(parent_size);
-            let node_size = known_dimensions.or(style_size);
             let node_min_size = style.min_size.maybe_resolve(parent_size);
             let node_max_size = style.max_size.maybe_resolve(parent_size);
+            let node_size = known_dimensions.or(style_size).maybe_apply_aspect_ratio(
+                style.aspect_ratio,
+                node_min_size,
+                node_max_size,
+            );
             (node_size, node_min_size, node_max_size)
         }
     };
@@ -55,32 +60,47 @@ pub(crate) fn compute(
         return Size { width, height }.maybe_clamp(node_min_size, node_max_size);
     };
 
+    // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
+    // This is not a bug, but is how CSS is specified (see: https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+
     if tree.needs_measure(node) {
+        // The measure function sizes the content box, so padding and border are removed from the sizes it is given and
+        // added back to the size that it returns
+        let content_box_inset = Size {
+            width: padding.horizontal_axis_sum() + border.horizontal_axis_sum(),
+            height: padding.vertical_axis_sum() + border.vertical_axis_sum(),
+        };
+
         // Compute available space
         let available_space = Size {
-            width: available_space
-                .width
-                .maybe_set(node_size.width)
-                .maybe_set(node_max_size.width)
-                .map_definite_value(|size| size.maybe_clamp(node_min_size.width, node_max_size.width)),
+            width: available_space.width.maybe_set(node_size.width).maybe_set(node_max_size.width).map_definite_value(
+                |size| (size.maybe_clamp(node_min_size.width, node_max_size.width) - content_box_inset.width).max(0.0),
+            ),
             height: available_space
                 .height
                 .maybe_set(node_size.height)
                 .maybe_set(node_max_size.height)
-                .map_definite_value(|size| size.maybe_clamp(node_min_size.height, node_max_size.height)),
+                .map_definite_value(|size| {
+                    (size.maybe_clamp(node_min_size.height, node_max_size.height) - content_box_inset.height).max(0.0)
+                }),
+        };
+        let known_dimensions = Size {
+            width: known_dimensions.width.map(|width| (width - content_box_inset.width).max(0.0)),
+            height: known_dimensions.height.map(|height| (height - content_box_inset.height).max(0.0)),
         };
 
         // Measure node
-        let measured_size = tree.measure_node(node, known_dimensions, available_space);
+        let measured_size = tree.measure_node_with_max_lines(node, known_dimensions, available_space, style.max_lines);
+        let measured_size = Size {
+            width: measured_size.width + content_box_inset.width,
+            height: measured_size.height + content_box_inset.height,
+        };
 
         return node_size.unwrap_or(measured_size).maybe_clamp(node_min_size, node_max_size);
     }
 
-    // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
-    // This is not a bug, but is how CSS is specified (see: https://developer.mozilla.org/en-US/docs/Web/CSS/padding#values)
-    let padding = style.padding.resolve_or_zero(parent_size.width);
-    let border = style.border.resolve_or_zero(parent_size.width);
-
     Size {
         width: node_size
             .width
@@ -7,13 +7,18 @@ pub(crate) mod leaf;
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
+#[cfg(feature = "rayon")]
+pub(crate) mod parallel;
+
 use crate::data::CACHE_SIZE;
 use crate::error::TaffyError;
 use crate::geometry::{Point, Size};
 use crate::layout::{Cache, Layout, RunMode, SizingMode};
+use crate::math::MaybeMath;
 use crate::node::Node;
-use crate::style::{AvailableSpace, Display};
-use crate::sys::round;
+use crate::resolve::{MaybeResolve, ResolveOrZero};
+use crate::style::{AvailableSpace, ContentVisibility, Display, Overflow, Position};
+use crate::sys::{abs, round};
 use crate::tree::LayoutTree;
 
 #[cfg(feature = "debug")]
@@ -25,26 +30,97 @@ pub fn compute_layout(
     root: Node,
     available_space: Size<AvailableSpace>,
 ) -> Result<(), TaffyError> {
+    compute_layout_with_callback(tree, root, available_space, |_, _| {})
+}
+
+/// Updates the stored layout of the provided `node` and its children, calling `callback` with the final layout of each node
+///
+/// The callback is called exactly once for each node that was laid out, in depth-first order, after the layout of that node
+/// has been rounded.
+pub fn compute_layout_with_callback(
+    tree: &mut impl LayoutTree,
+    root: Node,
+    available_space: Size<AvailableSpace>,
+    callback: impl FnMut(Node, &Layout),
+) -> Result<(), TaffyError> {
+    let available_space = infinite_space_as_max_content(available_space);
+    compute_layout_in(tree, root, available_space, available_space.into_options(), callback)
+}
+
+/// Updates the stored layout of the provided `node` and its children, resolving the root's percentage styles against
+/// `parent_size` rather than against `available_space`
+///
+/// This is useful when the tree is embedded in a layout that was computed externally. Cached layout results do not record
+/// the parent size they were computed with, so `root` should be marked dirty whenever `parent_size` changes.
+pub fn compute_layout_in(
+    tree: &mut impl LayoutTree,
+    root: Node,
+    available_space: Size<AvailableSpace>,
+    parent_size: Size<Option<f32>>,
+    callback: impl FnMut(Node, &Layout),
+) -> Result<(), TaffyError> {
+    compute_root_layout(tree, root, Size::NONE, available_space, parent_size, callback)
+}
+
+/// Updates the stored layout of the provided `root` and its children, fixing the size of `root` in each axis in which
+/// `known_dimensions` is `Some`
+pub(crate) fn compute_root_layout(
+    tree: &mut impl LayoutTree,
+    root: Node,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    parent_size: Size<Option<f32>>,
+    mut callback: impl FnMut(Node, &Layout),
+) -> Result<(), TaffyError> {
+    #[cfg(any(debug_assertions, feature = "validate"))]
+    validate_styles(tree, root)?;
+    let available_space = infinite_space_as_max_content(available_space);
+
     // Recursively compute node layout
     let size = compute_node_layout(
         tree,
         root,
-        Size::NONE,
-        available_space.into_options(),
+        known_dimensions,
+        parent_size,
         available_space,
         RunMode::PeformLayout,
         SizingMode::InherentSize,
     );
 
-    let layout = Layout { order: 0, size, location: Point::ZERO };
+    let layout = Layout { order: 0, size, location: Point::ZERO, scroll_size: size };
     *tree.layout_mut(root) = layout;
 
+    // Fixed position nodes are laid out by their parent as if they were absolutely positioned, so they now need to be
+    // resized and moved such that they are positioned against the root instead
+    position_fixed_descendants(tree, root, size, Point::ZERO);
+
+    compute_scroll_size(tree, root);
+
     // Recursively round the layout's of this node and all children
-    round_layout(tree, root, 0.0, 0.0);
+    round_layout(tree, root, 0.0, 0.0, &mut callback);
 
     Ok(())
 }
 
+/// Computes the size of the provided `node` under `available_space` without updating any stored layouts
+pub(crate) fn compute_size(
+    tree: &mut impl LayoutTree,
+    node: Node,
+    available_space: Size<AvailableSpace>,
+) -> Result<Size<f32>, TaffyError> {
+    #[cfg(any(debug_assertions, feature = "validate"))]
+    validate_styles(tree, node)?;
+    Ok(compute_node_layout(
+        tree,
+        node,
+        Size::NONE,
+        Size::NONE,
+        available_space,
+        RunMode::ComputeSize,
+        SizingMode::InherentSize,
+    ))
+}
+
 /// Updates the stored layout of the provided `node` and its children
 fn compute_node_layout(
     tree: &mut impl LayoutTree,
@@ -59,12 +135,26 @@ fn compute_node_layout(
     NODE_LOGGER.push_node(node);
     #[cfg(feature = "debug")]
     println!();
+    #[cfg(feature = "tracing")]
+    let span = tracing::trace_span!(
+        "compute_node_layout",
+        node = %node,
+        display = ?tree.style(node).display,
+        cache_hit = tracing::field::Empty,
+    )
+    .entered();
+
+    let available_space = infinite_space_as_max_content(available_space);
 
     // First we check if we have a cached result for the given input
     let cache_run_mode = if tree.is_childless(node) { RunMode::PeformLayout } else { run_mode };
-    if let Some(cached_size) =
-        compute_from_cache(tree, node, known_dimensions, available_space, cache_run_mode, sizing_mode)
-    {
+    let cached_size = compute_from_cache(tree, node, known_dimensions, available_space, cache_run_mode, sizing_mode)
+        .or_else(|| {
+            compute_from_shared_cache(tree, node, known_dimensions, available_space, cache_run_mode, sizing_mode)
+        });
+    #[cfg(feature = "tracing")]
+    span.record("cache_hit", cached_size.is_some());
+    if let Some(cached_size) = cached_size {
         #[cfg(feature = "debug")]
         NODE_LOGGER.labelled_debug_log("CACHE", cached_size);
         #[cfg(feature = "debug")]
@@ -91,21 +181,49 @@ fn compute_node_layout(
     #[cfg(feature = "debug")]
     NODE_LOGGER.labelled_debug_log("available_space", available_space);
 
+    // Nodes with hidden contents are sized without descending into their children
+    let style = tree.style(node);
+    let is_skipped = style.display != Display::None && style.content_visibility == ContentVisibility::Hidden;
+
     // If this is a leaf node we can skip a lot of this function in some cases
-    let computed_size = if tree.is_childless(node) {
+    let computed_size = if is_skipped {
+        #[cfg(feature = "debug")]
+        NODE_LOGGER.log("Algo: skipped");
+        compute_skipped_size(tree, node, known_dimensions, parent_size)
+    } else if tree.is_childless(node) {
         #[cfg(feature = "debug")]
         NODE_LOGGER.log("Algo: leaf");
         self::leaf::compute(tree, node, known_dimensions, parent_size, available_space, run_mode, sizing_mode)
     } else {
+        // Containers with a measure function are sized by it (as if they were a leaf) rather than by their children,
+        // which are then laid out within that size
+        let known_dimensions = if tree.needs_measure(node) {
+            #[cfg(feature = "debug")]
+            NODE_LOGGER.log("Algo: measured container");
+            let measured_size = self::leaf::compute(
+                tree,
+                node,
+                known_dimensions,
+                parent_size,
+                available_space,
+                RunMode::ComputeSize,
+                sizing_mode,
+            );
+            measured_size.map(Some)
+        } else {
+            known_dimensions
+        };
+
         // println!("match {:?}", tree.style(node).display);
         match tree.style(node).display {
-            Display::Flex => {
+            // Contents nodes are flattened away by flexbox containers, so this is only reached in other contexts
+            Display::Flex | Display::Contents => {
                 #[cfg(feature = "debug")]
                 NODE_LOGGER.log("Algo: flexbox");
                 self::flexbox::compute(tree, node, known_dimensions, parent_size, available_space, run_mode)
             }
             #[cfg(feature = "grid")]
-            Display::Grid => self::grid::compute(tree, node, known_dimensions, parent_size, available_space),
+            Display::Grid => self::grid::compute(tree, node, known_dimensions, parent_size, available_space, run_mode),
             Display::None => {
                 #[cfg(feature = "debug")]
                 NODE_LOGGER.log("Algo: none");
@@ -116,8 +234,12 @@ fn compute_node_layout(
 
     // Cache result
     let cache_slot = compute_cache_slot(known_dimensions, available_space);
-    *tree.cache_mut(node, cache_slot) =
-        Some(Cache { known_dimensions, available_space, run_mode: cache_run_mode, cached_size: computed_size });
+    store_in_cache(
+        tree,
+        node,
+        cache_slot,
+        Cache { known_dimensions, available_space, run_mode: cache_run_mode, cached_size: computed_size },
+    );
 
     #[cfg(feature = "debug")]
     NODE_LOGGER.labelled_debug_log("RESULT", computed_size);
@@ -127,6 +249,110 @@ fn compute_node_layout(
     computed_size
 }
 
+/// Returns an error if the style of `node` or of any of its descendants contains a NaN or infinite value
+///
+/// Such values would otherwise propagate silently through the layout algorithms and result in NaN layouts. As this walks
+/// the entire tree, it only runs in debug builds or when the `validate` feature is enabled.
+#[cfg(any(debug_assertions, feature = "validate"))]
+pub(crate) fn validate_styles(tree: &impl LayoutTree, node: Node) -> Result<(), TaffyError> {
+    use crate::style::{Dimension, LengthPercentageAuto};
+
+    /// Whether the value of a dimension (if any) is finite
+    fn is_finite(dimension: impl Into<Dimension>) -> bool {
+        match dimension.into() {
+            Dimension::Points(value) | Dimension::Percent(value) => value.is_finite(),
+            Dimension::Auto => true,
+        }
+    }
+
+    /// Whether the values of the grid track sizing functions of a node (if any) are finite
+    #[cfg(feature = "grid")]
+    fn grid_tracks_are_finite(style: &crate::style::Style) -> bool {
+        use crate::style::{
+            MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
+        };
+
+        let track_is_finite = |track: &NonRepeatedTrackSizingFunction| {
+            let min_is_finite = match track.min {
+                MinTrackSizingFunction::Fixed(value) => is_finite(value),
+                _ => true,
+            };
+            let max_is_finite = match track.max {
+                MaxTrackSizingFunction::Fixed(value) | MaxTrackSizingFunction::FitContent(value) => is_finite(value),
+                MaxTrackSizingFunction::Flex(fraction) => fraction.is_finite(),
+                _ => true,
+            };
+            min_is_finite && max_is_finite
+        };
+        let templates_are_finite =
+            style.grid_template_rows.iter().chain(style.grid_template_columns.tracks()).all(|track| match track {
+                TrackSizingFunction::Single(track) => track_is_finite(track),
+                TrackSizingFunction::AutoRepeat(_, tracks) => tracks.iter().all(track_is_finite),
+            });
+        templates_are_finite && style.grid_auto_rows.iter().chain(style.grid_auto_columns.iter()).all(track_is_finite)
+    }
+
+    let style = tree.style(node);
+    let sizes_are_finite = [style.size, style.min_size, style.max_size]
+        .into_iter()
+        .all(|size| is_finite(size.width) && is_finite(size.height));
+    let spacing_is_finite = [style.margin, style.inset]
+        .into_iter()
+        .chain([style.padding, style.border].map(|rect| rect.map(LengthPercentageAuto::from)))
+        .all(|rect| [rect.left, rect.right, rect.top, rect.bottom].into_iter().all(is_finite));
+    let flex_is_finite = is_finite(style.flex_basis)
+        && is_finite(style.gap.width)
+        && is_finite(style.gap.height)
+        && [style.flex_grow, style.flex_shrink, style.scrollbar_width, style.aspect_ratio.unwrap_or(1.0)]
+            .into_iter()
+            .all(f32::is_finite);
+    #[cfg(feature = "grid")]
+    let grid_is_finite = grid_tracks_are_finite(style);
+    #[cfg(not(feature = "grid"))]
+    let grid_is_finite = true;
+    if !(sizes_are_finite && spacing_is_finite && flex_is_finite && grid_is_finite) {
+        return Err(TaffyError::InvalidStyle { node });
+    }
+
+    tree.children(node).try_for_each(|child| validate_styles(tree, *child))
+}
+
+/// Converts infinite definite available space into a max-content constraint, which is what it is equivalent to.
+/// This prevents infinite available space from being used to resolve percentages or stretched sizes.
+#[inline]
+fn infinite_space_as_max_content(available_space: Size<AvailableSpace>) -> Size<AvailableSpace> {
+    available_space.map(|space| match space {
+        AvailableSpace::Definite(value) if value == f32::INFINITY => AvailableSpace::MaxContent,
+        _ => space,
+    })
+}
+
+/// Computes the size of a node with `content_visibility: hidden` without laying out its children
+///
+/// As its contents are not laid out, the node is sized as if it were empty: the size is taken from the known dimensions,
+/// then the node's `size` style, and finally from its padding and border (clamped by its `min_size` and `max_size`).
+fn compute_skipped_size(
+    tree: &impl LayoutTree,
+    node: Node,
+    known_dimensions: Size<Option<f32>>,
+    parent_size: Size<Option<f32>>,
+) -> Size<f32> {
+    let style = tree.style(node);
+    let min_size = style.min_size.maybe_resolve(parent_size);
+    let max_size = style.max_size.maybe_resolve(parent_size);
+    let style_size = style.size.maybe_resolve(parent_size).maybe_clamp(min_size, max_size);
+
+    // Percentage padding and border are resolved against the parent's width in both axes, as for leaf nodes
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding_border = Size {
+        width: padding.horizontal_axis_sum() + border.horizontal_axis_sum(),
+        height: padding.vertical_axis_sum() + border.vertical_axis_sum(),
+    };
+
+    known_dimensions.or(style_size).unwrap_or(padding_border.maybe_clamp(min_size, max_size))
+}
+
 /// Return the cache slot to cache the current computed result in
 ///
 /// ## Caching Strategy
@@ -171,6 +397,36 @@ fn compute_cache_slot(known_dimensions: Size<Option<f32>>, available_space: Size
     3 + (available_space.width == AvailableSpace::MinContent) as usize
 }
 
+/// Store a computation result in the cache slot returned by [`compute_cache_slot`]
+///
+/// Trees with fewer than [`CACHE_SIZE`] cache entries per node share entries between neighbouring slots. Trees with
+/// more entries use the additional entries to keep the most recent results that were evicted from their slot.
+#[inline]
+fn store_in_cache(tree: &mut impl LayoutTree, node: Node, slot: usize, entry: Cache) {
+    let cache_size = tree.cache_size();
+    if cache_size == 0 {
+        return;
+    }
+    if cache_size < CACHE_SIZE {
+        *tree.cache_mut(node, slot * cache_size / CACHE_SIZE) = Some(entry);
+        return;
+    }
+
+    let evicted = tree.cache_mut(node, slot).replace(entry);
+    // ComputeSize results are not reused by PerformLayout queries, so there is no point keeping them around once a
+    // PerformLayout result for the same slot is available
+    if let Some(evicted) = evicted.filter(|evicted| evicted.run_mode == entry.run_mode) {
+        // Shift the overflow entries along by one (dropping the oldest) to make room for the evicted entry
+        for idx in (CACHE_SIZE + 1..cache_size).rev() {
+            let previous = *tree.cache_mut(node, idx - 1);
+            *tree.cache_mut(node, idx) = previous;
+        }
+        if cache_size > CACHE_SIZE {
+            *tree.cache_mut(node, CACHE_SIZE) = Some(evicted);
+        }
+    }
+}
+
 /// Try to get the computation result from the cache.
 #[inline]
 fn compute_from_cache(
@@ -181,7 +437,8 @@ fn compute_from_cache(
     run_mode: RunMode,
     sizing_mode: SizingMode,
 ) -> Option<Size<f32>> {
-    for idx in 0..CACHE_SIZE {
+    let epsilon = tree.cache_epsilon();
+    for idx in 0..tree.cache_size() {
         let entry = tree.cache_mut(node, idx);
         if let Some(entry) = entry {
             // Cached ComputeSize results are not valid if we are running in PerformLayout mode
@@ -189,22 +446,21 @@ fn compute_from_cache(
                 return None;
             }
 
-            if (known_dimensions.width == entry.known_dimensions.width
-                || known_dimensions.width == Some(entry.cached_size.width))
-                && (known_dimensions.height == entry.known_dimensions.height
-                    || known_dimensions.height == Some(entry.cached_size.height))
+            if (is_roughly_equal(known_dimensions.width, entry.known_dimensions.width, epsilon)
+                || is_roughly_equal(known_dimensions.width, Some(entry.cached_size.width), epsilon))
+                && (is_roughly_equal(known_dimensions.height, entry.known_dimensions.height, epsilon)
+                    || is_roughly_equal(known_dimensions.height, Some(entry.cached_size.height), epsilon))
                 && (known_dimensions.width.is_some()
-                    || entry.available_space.width.is_roughly_equal(available_space.width)
+                    || entry.available_space.width.is_roughly_equal_within(available_space.width, epsilon)
                     || (sizing_mode == SizingMode::ContentSize
-                        && available_space.width.is_definite()
-                        && available_space.width.unwrap() >= entry.cached_size.width))
+                        && available_space.width.definite_or(f32::NEG_INFINITY) >= entry.cached_size.width))
                 && (known_dimensions.height.is_some()
-                    || entry.available_space.height.is_roughly_equal(available_space.height)
+                    || entry.available_space.height.is_roughly_equal_within(available_space.height, epsilon)
                     || (sizing_mode == SizingMode::ContentSize
-                        && available_space.height.is_definite()
-                        && available_space.height.unwrap() >= entry.cached_size.height))
+                        && available_space.height.definite_or(f32::NEG_INFINITY) >= entry.cached_size.height))
             {
-                return Some(entry.cached_size);
+                // Known dimensions may only roughly match those of the entry, so must override the cached size
+                return Some(known_dimensions.unwrap_or(entry.cached_size));
             }
         }
     }
@@ -212,12 +468,50 @@ fn compute_from_cache(
     None
 }
 
+/// Whether two optional sizes are both `None`, or are both `Some` and within `epsilon` of each other
+#[inline]
+fn is_roughly_equal(a: Option<f32>, b: Option<f32>, epsilon: f32) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => abs(a - b) < epsilon,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Try to get the computation result from the cache of a node that shares its cache with this one (see
+/// [`LayoutTree::shared_cache_peer`]), storing it in the cache of this node if one is found
+#[inline]
+fn compute_from_shared_cache(
+    tree: &mut impl LayoutTree,
+    node: Node,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    run_mode: RunMode,
+    sizing_mode: SizingMode,
+) -> Option<Size<f32>> {
+    // Reusing another node's result would leave the descendants of this node without a layout
+    if run_mode == RunMode::PeformLayout && !tree.is_childless(node) {
+        return None;
+    }
+
+    let peer = tree.shared_cache_peer(node)?;
+    let cached_size = compute_from_cache(tree, peer, known_dimensions, available_space, run_mode, sizing_mode)?;
+    let cache_slot = compute_cache_slot(known_dimensions, available_space);
+    store_in_cache(tree, node, cache_slot, Cache { known_dimensions, available_space, run_mode, cached_size });
+    Some(cached_size)
+}
+
 /// Creates a layout for this node and its children, recursively.
 /// Each hidden node has zero size and is placed at the origin
 fn perform_hidden_layout(tree: &mut impl LayoutTree, node: Node) -> Size<f32> {
     /// Recursive function to apply hidden layout to all descendents
     fn perform_hidden_layout_inner(tree: &mut impl LayoutTree, node: Node, order: u32) {
         *tree.layout_mut(node) = Layout::with_order(order);
+
+        // The cached results no longer match the stored layout, so must not be reused if the node is shown again
+        for idx in 0..tree.cache_size() {
+            *tree.cache_mut(node, idx) = None;
+        }
         for order in 0..tree.child_count(node) {
             perform_hidden_layout_inner(tree, tree.child(node, order), order as _);
         }
@@ -230,8 +524,139 @@ fn perform_hidden_layout(tree: &mut impl LayoutTree, node: Node) -> Size<f32> {
     Size::ZERO
 }
 
+/// Lays out each `Position::Fixed` descendant of `node` against the root, whose size is `root_size`
+///
+/// `node_position` is the position of `node` relative to the root.
+fn position_fixed_descendants(tree: &mut impl LayoutTree, node: Node, root_size: Size<f32>, node_position: Point<f32>) {
+    for index in 0..tree.child_count(node) {
+        let child = tree.child(node, index);
+        let child_style = tree.style(child);
+        if child_style.display == Display::None {
+            continue;
+        }
+
+        if child_style.position != Position::Fixed {
+            let location = tree.layout(child).location;
+            let child_position = Point { x: node_position.x + location.x, y: node_position.y + location.y };
+            position_fixed_descendants(tree, child, root_size, child_position);
+            continue;
+        }
+
+        let root_width = Some(root_size.width);
+        let root_height = Some(root_size.height);
+
+        // X-axis
+        let left = child_style.inset.left.maybe_resolve(root_width);
+        let margin_left = child_style.margin.left.maybe_resolve(root_width);
+        let right = child_style.inset.right.maybe_resolve(root_width);
+        let margin_right = child_style.margin.right.maybe_resolve(root_width);
+
+        // Y-axis
+        let top = child_style.inset.top.maybe_resolve(root_height);
+        let margin_top = child_style.margin.top.maybe_resolve(root_height);
+        let bottom = child_style.inset.bottom.maybe_resolve(root_height);
+        let margin_bottom = child_style.margin.bottom.maybe_resolve(root_height);
+
+        // Compute known dimensions from min/max/inherent size styles
+        let root_size_option = root_size.map(Some);
+        let style_size = child_style.size.maybe_resolve(root_size_option);
+        let min_size = child_style.min_size.maybe_resolve(root_size_option);
+        let max_size = child_style.max_size.maybe_resolve(root_size_option);
+        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+        // Fill in width from left/right and height from top/bottom if both are set
+        let start = left.maybe_add(margin_left);
+        let end = right.maybe_add(margin_right);
+        if known_dimensions.width.is_none() && start.is_some() && end.is_some() {
+            known_dimensions.width = Some(root_width.maybe_sub(start).maybe_sub(end).unwrap_or(0.0).max(0.0));
+        }
+        let start = top.maybe_add(margin_top);
+        let end = bottom.maybe_add(margin_bottom);
+        if known_dimensions.height.is_none() && start.is_some() && end.is_some() {
+            known_dimensions.height = Some(root_height.maybe_sub(start).maybe_sub(end).unwrap_or(0.0).max(0.0));
+        }
+
+        let size = compute_node_layout(
+            tree,
+            child,
+            known_dimensions,
+            root_size_option,
+            root_size.map(AvailableSpace::Definite),
+            RunMode::PeformLayout,
+            SizingMode::ContentSize,
+        );
+
+        // Nodes without any insets in an axis keep the static position that their parent gave them in that axis
+        let static_location = tree.layout(child).location;
+        let x = match (left, right) {
+            (Some(left), _) => left + margin_left.unwrap_or(0.0) - node_position.x,
+            (None, Some(right)) => root_size.width - right - size.width - margin_right.unwrap_or(0.0) - node_position.x,
+            (None, None) => static_location.x,
+        };
+        let y = match (top, bottom) {
+            (Some(top), _) => top + margin_top.unwrap_or(0.0) - node_position.y,
+            (None, Some(bottom)) => {
+                root_size.height - bottom - size.height - margin_bottom.unwrap_or(0.0) - node_position.y
+            }
+            (None, None) => static_location.y,
+        };
+
+        let layout = tree.layout_mut(child);
+        layout.size = size;
+        layout.location = Point { x, y };
+
+        let child_position = Point { x: node_position.x + x, y: node_position.y + y };
+        position_fixed_descendants(tree, child, root_size, child_position);
+    }
+}
+
+/// Updates the stored [`Layout::scroll_size`] of `node` and all of its descendants, and returns that of `node`
+fn compute_scroll_size(tree: &mut impl LayoutTree, node: Node) -> Size<f32> {
+    let mut scroll_size = tree.layout(node).size;
+    for index in 0..tree.child_count(node) {
+        let child = tree.child(node, index);
+        let child_scroll_size = compute_scroll_size(tree, child);
+        let child_style = tree.style(child);
+        if child_style.display == Display::None {
+            continue;
+        }
+
+        // Content that overflows a child is only visible outside of it if the child does not clip it
+        let child_layout = tree.layout(child);
+        let child_extent = Size {
+            width: if child_style.overflow.x == Overflow::Visible {
+                child_scroll_size.width
+            } else {
+                child_layout.size.width
+            },
+            height: if child_style.overflow.y == Overflow::Visible {
+                child_scroll_size.height
+            } else {
+                child_layout.size.height
+            },
+        };
+        scroll_size.width = scroll_size.width.max(child_layout.location.x + child_extent.width);
+        scroll_size.height = scroll_size.height.max(child_layout.location.y + child_extent.height);
+    }
+
+    tree.layout_mut(node).scroll_size = scroll_size;
+    scroll_size
+}
+
 /// Rounds the calculated [`NodeData`] according to the spec
-fn round_layout(tree: &mut impl LayoutTree, root: Node, abs_x: f32, abs_y: f32) {
+fn round_layout(
+    tree: &mut impl LayoutTree,
+    root: Node,
+    abs_x: f32,
+    abs_y: f32,
+    callback: &mut impl FnMut(Node, &Layout),
+) {
+    #[cfg(feature = "test-helpers")]
+    {
+        let unrounded_layout = *tree.layout(root);
+        tree.record_unrounded_layout(root, unrounded_layout);
+    }
+
     let layout = tree.layout_mut(root);
     let abs_x = abs_x + layout.location.x;
     let abs_y = abs_y + layout.location.y;
@@ -242,19 +667,32 @@ fn round_layout(tree: &mut impl LayoutTree, root: Node, abs_x: f32, abs_y: f32)
     layout.size.width = round(layout.size.width);
     layout.size.height = round(layout.size.height);
 
+    layout.scroll_size.width = round(layout.scroll_size.width);
+    layout.scroll_size.height = round(layout.scroll_size.height);
+
+    callback(root, layout);
+
     // Satisfy the borrow checker here by re-indexing to shorten the lifetime to the loop scope
     for x in 0..tree.child_count(root) {
         let child = tree.child(root, x);
-        round_layout(tree, child, abs_x, abs_y);
+        round_layout(tree, child, abs_x, abs_y, callback);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::perform_hidden_layout;
+    use super::{compute_node_layout, perform_hidden_layout};
+    #[cfg(any(debug_assertions, feature = "validate"))]
+    use crate::error::TaffyError;
     use crate::geometry::{Point, Size};
-    use crate::style::{Display, Style};
+    use crate::layout::{RunMode, SizingMode};
+    use crate::node::MeasureFunc;
+    use crate::prelude::TaffyMaxContent;
+    use crate::style::{AvailableSpace, Display, FlexDirection, Style};
+    use crate::style_helpers::points;
     use crate::Taffy;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     #[test]
     fn hidden_layout_should_hide_recursively() {
@@ -287,4 +725,183 @@ mod tests {
             }
         }
     }
+
+    /// Sizes a measured leaf under three queries of the same kind (the first of which is repeated),
+    /// and returns the number of times that the leaf was measured
+    fn count_measures_of_repeated_queries<const CACHE: usize>(mut taffy: Taffy<CACHE>) -> usize {
+        let measure_count = Arc::new(AtomicUsize::new(0));
+        let counter = measure_count.clone();
+        let node = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Boxed(Box::new(move |known_dimensions: Size<Option<f32>>, _| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Size { width: known_dimensions.width.unwrap_or(10.0), height: 10.0 }
+                })),
+            )
+            .unwrap();
+
+        for width in [10.0, 20.0, 10.0] {
+            compute_node_layout(
+                &mut taffy,
+                node,
+                Size { width: Some(width), height: None },
+                Size::NONE,
+                Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+                RunMode::ComputeSize,
+                SizingMode::InherentSize,
+            );
+        }
+
+        measure_count.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "validate"))]
+    fn non_finite_style_is_an_error() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(f32::NAN, 10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        let result = taffy.compute_layout(root, Size::MAX_CONTENT);
+        assert!(matches!(result, Err(TaffyError::InvalidStyle { node }) if node == child));
+
+        taffy.set_style(child, Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    #[cfg(all(feature = "grid", any(debug_assertions, feature = "validate")))]
+    fn non_finite_grid_track_is_an_error() {
+        let mut taffy = Taffy::new();
+        let root = taffy
+            .new_leaf(Style { display: Display::Grid, grid_auto_rows: vec![points(f32::NAN)], ..Default::default() })
+            .unwrap();
+
+        let result = taffy.compute_layout(root, Size::MAX_CONTENT);
+        assert!(matches!(result, Err(TaffyError::InvalidStyle { node }) if node == root));
+    }
+
+    /// Sizes a measured leaf under two definite available widths 0.1 apart with the given cache epsilon,
+    /// and returns the number of times that the leaf was measured
+    fn count_measures_of_nearby_queries(epsilon: f32) -> usize {
+        let mut taffy = Taffy::new();
+        taffy.set_cache_epsilon(epsilon);
+        let measure_count = Arc::new(AtomicUsize::new(0));
+        let counter = measure_count.clone();
+        let node = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Boxed(Box::new(move |_, _| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Size { width: 10.0, height: 10.0 }
+                })),
+            )
+            .unwrap();
+
+        for width in [100.0, 100.1] {
+            compute_node_layout(
+                &mut taffy,
+                node,
+                Size::NONE,
+                Size::NONE,
+                Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent },
+                RunMode::ComputeSize,
+                SizingMode::InherentSize,
+            );
+        }
+
+        measure_count.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn cache_epsilon_applies_to_known_dimensions() {
+        let mut taffy = Taffy::new();
+        taffy.set_cache_epsilon(0.5);
+        let measure_count = Arc::new(AtomicUsize::new(0));
+        let counter = measure_count.clone();
+        let node = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Boxed(Box::new(move |known_dimensions: Size<Option<f32>>, _| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Size { width: known_dimensions.width.unwrap_or(10.0), height: 10.0 }
+                })),
+            )
+            .unwrap();
+
+        let sizes = [100.0, 100.1].map(|width| {
+            compute_node_layout(
+                &mut taffy,
+                node,
+                Size { width: Some(width), height: None },
+                Size::NONE,
+                Size::MAX_CONTENT,
+                RunMode::ComputeSize,
+                SizingMode::InherentSize,
+            )
+        });
+
+        // The second query reuses the cached result, but still honours its own known width
+        assert_eq!(measure_count.load(Ordering::SeqCst), 1);
+        assert_eq!(sizes[1], Size { width: 100.1, height: 10.0 });
+    }
+
+    #[test]
+    fn cache_epsilon_controls_reuse_of_nearby_queries() {
+        assert_eq!(count_measures_of_nearby_queries(f32::EPSILON), 2);
+        assert_eq!(count_measures_of_nearby_queries(0.5), 1);
+        assert_eq!(count_measures_of_nearby_queries(0.01), 2);
+    }
+
+    /// Lays out a list of 100 identical rows which each contain a measured leaf, and returns the number of times that
+    /// the leaves were measured
+    fn count_measures_of_identical_rows(shared_cache: bool) -> usize {
+        static MEASURE_COUNT: AtomicUsize = AtomicUsize::new(0);
+        fn measure(known_dimensions: Size<Option<f32>>, _: Size<AvailableSpace>) -> Size<f32> {
+            MEASURE_COUNT.fetch_add(1, Ordering::SeqCst);
+            Size { width: known_dimensions.width.unwrap_or(10.0), height: known_dimensions.height.unwrap_or(10.0) }
+        }
+
+        let mut taffy = Taffy::new();
+        if shared_cache {
+            taffy.enable_shared_cache();
+        }
+        let rows: Vec<_> = (0..100)
+            .map(|_| {
+                let leaf = taffy.new_leaf_with_measure(Style::default(), MeasureFunc::Raw(measure)).unwrap();
+                let row_style = Style { padding: points(2.0), ..Default::default() };
+                taffy.new_with_children(row_style, &[leaf]).unwrap()
+            })
+            .collect();
+        let root_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+        let root = taffy.new_with_children(root_style, &rows).unwrap();
+
+        let before = MEASURE_COUNT.load(Ordering::SeqCst);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        for row in rows {
+            assert_eq!(taffy.layout(row).unwrap().size, Size { width: 14.0, height: 14.0 });
+        }
+        MEASURE_COUNT.load(Ordering::SeqCst) - before
+    }
+
+    #[test]
+    fn shared_cache_reuses_sizes_of_identical_siblings() {
+        let unshared = count_measures_of_identical_rows(false);
+        let shared = count_measures_of_identical_rows(true);
+        assert!(unshared >= 100);
+        assert!(shared * 10 < unshared, "{shared} measures with a shared cache and {unshared} without");
+    }
+
+    #[test]
+    fn default_cache_overwrites_queries_of_the_same_kind() {
+        assert_eq!(count_measures_of_repeated_queries(Taffy::new()), 3);
+    }
+
+    #[test]
+    fn larger_cache_keeps_evicted_queries() {
+        let taffy: Taffy<7> = Taffy::with_capacity_and_cache(16);
+        assert_eq!(count_measures_of_repeated_queries(taffy), 2);
+    }
 }
@@ -0,0 +1,287 @@
+//! Parallel computation of independent subtrees of a [`Taffy`] tree
+//!
+//! The layout algorithms are written against `&mut impl LayoutTree`, so they cannot be run concurrently against the
+//! same [`Taffy`] instance. Instead each independent subtree is laid out against a [`SubtreeView`] which reads from a
+//! shared `&Taffy` and buffers any writes (layouts and cache entries) locally. The buffered writes are merged back into
+//! the tree once all subtrees have been computed, after which a regular serial layout pass is run. That pass will hit
+//! the cache for every subtree that was computed in parallel, so the final result is identical to a serial layout.
+use std::collections::HashMap;
+
+use rayon::prelude::*;
+
+use super::{compute_layout, compute_node_layout};
+use crate::error::TaffyError;
+use crate::geometry::Size;
+#[cfg(feature = "grid")]
+use crate::layout::SubgridColumns;
+use crate::layout::{Cache, Layout, LayoutWarning, RunMode, SizingMode};
+use crate::node::{Node, Taffy};
+use crate::style::{AvailableSpace, Dimension, Display, LengthPercentage, Style};
+use crate::tree::LayoutTree;
+
+/// Updates the stored layout of the provided `root` and its children, computing the layout of independent
+/// children of the root in parallel.
+pub(crate) fn compute_layout_parallel<const CACHE: usize>(
+    taffy: &mut Taffy<CACHE>,
+    root: Node,
+    available_space: Size<AvailableSpace>,
+) -> Result<(), TaffyError> {
+    #[cfg(any(debug_assertions, feature = "validate"))]
+    super::validate_styles(taffy, root)?;
+    let independent_children: Vec<(Node, Size<f32>)> = taffy.children[root]
+        .iter()
+        .filter(|child| !taffy.children[**child].is_empty())
+        .filter_map(|child| independent_size(&taffy.nodes[*child].style).map(|size| (*child, size)))
+        .collect();
+
+    // Lay out each independent subtree against a read-only view of the tree
+    let shared: &Taffy<CACHE> = taffy;
+    let views: Vec<SubtreeView<CACHE>> = independent_children
+        .par_iter()
+        .map(|(child, size)| {
+            let mut view = SubtreeView::new(shared);
+            compute_node_layout(
+                &mut view,
+                *child,
+                size.map(Some),
+                Size::NONE,
+                size.map(AvailableSpace::Definite),
+                RunMode::PeformLayout,
+                SizingMode::InherentSize,
+            );
+            view
+        })
+        .collect();
+
+    // Merge the buffered results back into the tree
+    let writes: Vec<_> = views.into_iter().map(SubtreeView::into_writes).collect();
+    for writes in writes {
+        for (node, layout) in writes.layouts {
+            taffy.nodes[node].layout = layout;
+        }
+        for (node, cache) in writes.caches {
+            taffy.nodes[node].size_cache = cache;
+        }
+        for warning in writes.warnings {
+            taffy.record_warning(warning);
+        }
+        for (node, line) in writes.flex_lines {
+            taffy.record_flex_line(node, line);
+        }
+        #[cfg(feature = "grid")]
+        for (node, (columns, rows)) in writes.grid_lines {
+            taffy.record_grid_lines(node, columns, rows);
+        }
+        #[cfg(feature = "grid")]
+        for (node, columns) in writes.subgrid_columns {
+            taffy.record_subgrid_columns(node, columns);
+        }
+    }
+
+    // The serial pass positions every child of the root and reuses the cached results of the parallel pass
+    compute_layout(taffy, root, available_space)
+}
+
+/// Returns the size of a node if its layout cannot be affected by its parent or siblings.
+///
+/// We are deliberately conservative here: the node must have a definite size in both axes, and must not have any
+/// percentage-based sizing or spacing styles (as these would resolve against the size of the parent).
+fn independent_size(style: &Style) -> Option<Size<f32>> {
+    /// Whether a dimension resolves without a parent size
+    fn is_absolute(dimension: Dimension) -> bool {
+        !matches!(dimension, Dimension::Percent(_))
+    }
+    /// Whether a length resolves without a parent size
+    fn is_absolute_length(length: LengthPercentage) -> bool {
+        !matches!(length, LengthPercentage::Percent(_))
+    }
+
+    if matches!(style.display, Display::None | Display::Contents) {
+        return None;
+    }
+    let size = match style.size {
+        Size { width: Dimension::Points(width), height: Dimension::Points(height) } => Size { width, height },
+        _ => return None,
+    };
+    let sizes_are_absolute =
+        [style.min_size, style.max_size].iter().all(|size| is_absolute(size.width) && is_absolute(size.height));
+    let spacing_is_absolute = [style.padding, style.border]
+        .iter()
+        .all(|rect| [rect.left, rect.right, rect.top, rect.bottom].into_iter().all(is_absolute_length));
+
+    (sizes_are_absolute && spacing_is_absolute).then_some(size)
+}
+
+/// The writes buffered by a [`SubtreeView`], detached from the tree they were read from
+struct BufferedWrites<const CACHE: usize> {
+    /// Layouts that have been written through the view
+    layouts: HashMap<Node, Layout>,
+    /// Cache entries that have been written through the view
+    caches: HashMap<Node, [Option<Cache>; CACHE]>,
+    /// Warnings that have been recorded through the view
+    warnings: Vec<LayoutWarning>,
+    /// Flex line indices that have been recorded through the view
+    flex_lines: HashMap<Node, Option<usize>>,
+    /// Grid track edges that have been recorded through the view
+    #[cfg(feature = "grid")]
+    grid_lines: HashMap<Node, (Vec<f32>, Vec<f32>)>,
+    /// Subgrid columns that have been recorded through the view
+    #[cfg(feature = "grid")]
+    subgrid_columns: HashMap<Node, SubgridColumns>,
+}
+
+/// A view of a [`Taffy`] tree that reads styles and the tree structure from a shared reference and
+/// buffers any writes locally so that multiple views can be used from different threads at once.
+struct SubtreeView<'a, const CACHE: usize> {
+    /// The tree being laid out
+    taffy: &'a Taffy<CACHE>,
+    /// Layouts that have been written through this view
+    layouts: HashMap<Node, Layout>,
+    /// Cache entries that have been written through this view
+    caches: HashMap<Node, [Option<Cache>; CACHE]>,
+    /// Warnings that have been recorded through this view
+    warnings: Vec<LayoutWarning>,
+    /// Flex line indices that have been recorded through this view
+    flex_lines: HashMap<Node, Option<usize>>,
+    /// Grid track edges that have been recorded through this view
+    #[cfg(feature = "grid")]
+    grid_lines: HashMap<Node, (Vec<f32>, Vec<f32>)>,
+    /// Subgrid columns that have been recorded through this view
+    #[cfg(feature = "grid")]
+    subgrid_columns: HashMap<Node, SubgridColumns>,
+}
+
+impl<'a, const CACHE: usize> SubtreeView<'a, CACHE> {
+    /// Creates a new view with no buffered writes
+    fn new(taffy: &'a Taffy<CACHE>) -> Self {
+        Self {
+            taffy,
+            layouts: HashMap::new(),
+            caches: HashMap::new(),
+            warnings: Vec::new(),
+            flex_lines: HashMap::new(),
+            #[cfg(feature = "grid")]
+            grid_lines: HashMap::new(),
+            #[cfg(feature = "grid")]
+            subgrid_columns: HashMap::new(),
+        }
+    }
+
+    /// Releases the borrow of the tree, returning the writes buffered by this view
+    fn into_writes(self) -> BufferedWrites<CACHE> {
+        BufferedWrites {
+            layouts: self.layouts,
+            caches: self.caches,
+            warnings: self.warnings,
+            flex_lines: self.flex_lines,
+            #[cfg(feature = "grid")]
+            grid_lines: self.grid_lines,
+            #[cfg(feature = "grid")]
+            subgrid_columns: self.subgrid_columns,
+        }
+    }
+}
+
+impl<'a, const CACHE: usize> LayoutTree for SubtreeView<'a, CACHE> {
+    type ChildIter<'b>
+        = <Taffy<CACHE> as LayoutTree>::ChildIter<'b>
+    where
+        Self: 'b;
+
+    fn children(&self, node: Node) -> Self::ChildIter<'_> {
+        self.taffy.children[node].iter()
+    }
+
+    fn child_count(&self, node: Node) -> usize {
+        self.taffy.children[node].len()
+    }
+
+    fn is_childless(&self, node: Node) -> bool {
+        self.taffy.children[node].is_empty()
+    }
+
+    fn child(&self, node: Node, index: usize) -> Node {
+        self.taffy.children[node][index]
+    }
+
+    fn parent(&self, node: Node) -> Option<Node> {
+        self.taffy.parents.get(node).copied().flatten()
+    }
+
+    fn style(&self, node: Node) -> &Style {
+        &self.taffy.nodes[node].style
+    }
+
+    fn layout(&self, node: Node) -> &Layout {
+        self.layouts.get(&node).unwrap_or(&self.taffy.nodes[node].layout)
+    }
+
+    fn layout_mut(&mut self, node: Node) -> &mut Layout {
+        let taffy = self.taffy;
+        self.layouts.entry(node).or_insert_with(|| taffy.nodes[node].layout)
+    }
+
+    fn mark_dirty(&mut self, node: Node) -> Result<(), TaffyError> {
+        self.caches.insert(node, [None; CACHE]);
+        Ok(())
+    }
+
+    fn measure_node(
+        &self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> Size<f32> {
+        self.taffy.measure_node(node, known_dimensions, available_space)
+    }
+
+    fn measure_node_with_max_lines(
+        &self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        max_lines: Option<u32>,
+    ) -> Size<f32> {
+        self.taffy.measure_node_with_max_lines(node, known_dimensions, available_space, max_lines)
+    }
+
+    fn needs_measure(&self, node: Node) -> bool {
+        self.taffy.needs_measure(node)
+    }
+
+    fn cache_size(&self) -> usize {
+        CACHE
+    }
+
+    fn cache_epsilon(&self) -> f32 {
+        self.taffy.cache_epsilon
+    }
+
+    fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
+        let taffy = self.taffy;
+        &mut self.caches.entry(node).or_insert_with(|| taffy.nodes[node].size_cache)[index]
+    }
+
+    fn record_flex_line(&mut self, node: Node, line: Option<usize>) {
+        self.flex_lines.insert(node, line);
+    }
+
+    fn record_warning(&mut self, warning: LayoutWarning) {
+        self.warnings.push(warning);
+    }
+
+    #[cfg(feature = "grid")]
+    fn record_grid_lines(&mut self, node: Node, columns: Vec<f32>, rows: Vec<f32>) {
+        self.grid_lines.insert(node, (columns, rows));
+    }
+
+    #[cfg(feature = "grid")]
+    fn record_subgrid_columns(&mut self, node: Node, columns: SubgridColumns) {
+        self.subgrid_columns.insert(node, columns);
+    }
+
+    #[cfg(feature = "grid")]
+    fn subgrid_columns(&self, node: Node) -> Option<&SubgridColumns> {
+        self.subgrid_columns.get(&node).or_else(|| self.taffy.subgrid_columns(node))
+    }
+}
@@ -5,13 +5,13 @@
 use crate::layout::{Cache, Layout};
 use crate::style::Style;
 
-/// The number of cache entries for each node in the tree
+/// The default number of cache entries for each node in the tree
 pub(crate) const CACHE_SIZE: usize = 5;
 
 /// Layout information for a given [`Node`](crate::node::Node)
 ///
 /// Stored in a [`Taffy`].
-pub(crate) struct NodeData {
+pub(crate) struct NodeData<const CACHE: usize = CACHE_SIZE> {
     /// The layout strategy used by this node
     pub(crate) style: Style,
     /// The results of the layout computation
@@ -21,14 +21,14 @@ pub(crate) struct NodeData {
     pub(crate) needs_measure: bool,
 
     /// The primary cached results of the layout computation
-    pub(crate) size_cache: [Option<Cache>; CACHE_SIZE],
+    pub(crate) size_cache: [Option<Cache>; CACHE],
 }
 
-impl NodeData {
+impl<const CACHE: usize> NodeData<CACHE> {
     /// Create the data for a new node
     #[must_use]
     pub const fn new(style: Style) -> Self {
-        Self { style, size_cache: [None; CACHE_SIZE], layout: Layout::new(), needs_measure: false }
+        Self { style, size_cache: [None; CACHE], layout: Layout::new(), needs_measure: false }
     }
 
     /// Marks a node and all of its parents (recursively) as dirty
@@ -36,6 +36,6 @@ impl NodeData {
     /// This clears any cached data and signals that the data must be recomputed.
     #[inline]
     pub fn mark_dirty(&mut self) {
-        self.size_cache = [None; CACHE_SIZE];
+        self.size_cache = [None; CACHE];
     }
 }
@@ -25,6 +25,20 @@ pub enum TaffyError {
     InvalidChildNode(Node),
     /// The supplied [`Node`] was not found in the [`Taffy`](crate::Taffy) instance.
     InvalidInputNode(Node),
+    /// The [`Style`](crate::style::Style) of a [`Node`] contains a NaN or infinite value, so it cannot be laid out.
+    ///
+    /// Styles are only checked in debug builds, or when the `validate` feature is enabled.
+    InvalidStyle {
+        /// The node whose style is invalid
+        node: Node,
+    },
+    /// Two adjacent sibling [`Node`]s are separated by a 1px gap (or overlap) that was introduced by rounding their layouts
+    RoundingGap {
+        /// The earlier of the two siblings
+        first: Node,
+        /// The later of the two siblings
+        second: Node,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -39,6 +53,12 @@ impl Display for TaffyError {
             }
             TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the Taffy instance"),
             TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the Taffy instance"),
+            TaffyError::InvalidStyle { node } => {
+                write!(f, "The style of Node {node:?} contains a NaN or infinite value")
+            }
+            TaffyError::RoundingGap { first, second } => {
+                write!(f, "Rounding left a 1px gap or overlap between sibling Nodes {first:?} and {second:?}")
+            }
         }
     }
 }
@@ -1,15 +1,16 @@
 //! Geometric primitives useful for layout
 
 use crate::style::{Dimension, FlexDirection};
+use core::fmt;
 use core::ops::Add;
 
 #[cfg(feature = "grid")]
 use crate::axis::AbstractAxis;
+use crate::math::MaybeMath;
 
 /// An axis-aligned UI rectangle
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
 pub struct Rect<T> {
     /// This can represent either the x-coordinate of the starting edge,
     /// or the amount of padding on the starting side.
@@ -42,6 +43,13 @@ impl<T: Default> Default for Rect<T> {
     }
 }
 
+/// Formats the rect compactly as `Rect(left, right, top, bottom)`
+impl<T: fmt::Display> fmt::Display for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rect({}, {}, {}, {})", self.left, self.right, self.top, self.bottom)
+    }
+}
+
 impl<T> Rect<T> {
     /// Applies the function `f` to all four sides of the rect
     ///
@@ -183,6 +191,12 @@ where
     }
 }
 
+impl Rect<Dimension> {
+    /// A [`Rect`] with [`Dimension::Auto`] on all sides
+    pub const AUTO: Rect<Dimension> =
+        Self { left: Dimension::Auto, right: Dimension::Auto, top: Dimension::Auto, bottom: Dimension::Auto };
+}
+
 impl Rect<f32> {
     /// Creates a new Rect with `0.0` as all parameters
     pub const ZERO: Rect<f32> = Self { left: 0.0, right: 0.0, top: 0.0, bottom: 0.0 };
@@ -195,9 +209,8 @@ impl Rect<f32> {
 }
 
 /// An abstract "line". Represents any type that has a start and an end
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
 pub struct Line<T> {
     /// The start position of a line
     pub start: T,
@@ -225,9 +238,8 @@ impl<T: Add + Copy> Line<T> {
 }
 
 /// The width and height of a [`Rect`]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
 pub struct Size<T> {
     /// The x extent of the rectangle
     pub width: T,
@@ -241,6 +253,13 @@ impl<T: Default> Default for Size<T> {
     }
 }
 
+/// Formats the size compactly as `Size(width × height)`
+impl<T: fmt::Display> fmt::Display for Size<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Size({} × {})", self.width, self.height)
+    }
+}
+
 // Generic Add impl for Size<T> + Size<U> where T + U has an Add impl
 impl<U, T: Add<U>> Add<Size<U>> for Size<T> {
     type Output = Size<<T as Add<U>>::Output>;
@@ -354,10 +373,22 @@ impl<T> Size<T> {
 impl Size<f32> {
     /// A [`Size`] with zero width and height
     pub const ZERO: Size<f32> = Self { width: 0.0, height: 0.0 };
+
+    /// Clamps each axis of the size between the corresponding axes of `min` and `max`
+    ///
+    /// `None` bounds are treated as unbounded. As in CSS, the minimum size wins if it is larger than the maximum size.
+    pub fn clamp(self, min: Size<Option<f32>>, max: Size<Option<f32>>) -> Size<f32> {
+        Size {
+            width: self.width.maybe_clamp(min.width, max.width),
+            height: self.height.maybe_clamp(min.height, max.height),
+        }
+    }
 }
 
 impl Size<Option<f32>> {
-    /// A [`Size`] with `None` width and height
+    /// A [`Size`] with `None` width and height, meaning that neither dimension is known
+    ///
+    /// This is not the same as [`Size::ZERO`], which is a known size of `0.0` in both dimensions.
     pub const NONE: Size<Option<f32>> = Self { width: None, height: None };
 
     /// A [`Size<Option<f32>>`] with `Some(width)` and `Some(height)` as parameters
@@ -365,6 +396,30 @@ impl Size<Option<f32>> {
     pub const fn new(width: f32, height: f32) -> Self {
         Size { width: Some(width), height: Some(height) }
     }
+
+    /// Fills in a missing axis from the other axis using `aspect_ratio` (width divided by height)
+    ///
+    /// This is the CSS "transferred size": the known axis is first clamped by its own `min` and `max`, and the size
+    /// transferred from it is then clamped by the `min` and `max` of the other axis. If both or neither axes are known,
+    /// or there is no aspect ratio, then the size is returned unchanged.
+    pub fn maybe_apply_aspect_ratio(
+        self,
+        aspect_ratio: Option<f32>,
+        min: Size<Option<f32>>,
+        max: Size<Option<f32>>,
+    ) -> Size<Option<f32>> {
+        match (aspect_ratio, self.width, self.height) {
+            (Some(ratio), Some(width), None) => {
+                let width = width.maybe_clamp(min.width, max.width);
+                Size { width: Some(width), height: Some((width / ratio).maybe_clamp(min.height, max.height)) }
+            }
+            (Some(ratio), None, Some(height)) => {
+                let height = height.maybe_clamp(min.height, max.height);
+                Size { width: Some((height * ratio).maybe_clamp(min.width, max.width)), height: Some(height) }
+            }
+            _ => self,
+        }
+    }
 }
 
 impl<T> Size<Option<T>> {
@@ -380,6 +435,9 @@ impl<T> Size<Option<T>> {
 }
 
 impl Size<Dimension> {
+    /// A [`Size`] with [`Dimension::Auto`] width and height
+    pub const AUTO: Size<Dimension> = Self { width: Dimension::Auto, height: Dimension::Auto };
+
     /// Generates a [`Size<Dimension>`] using [`Dimension::Points`] values
     #[must_use]
     pub const fn from_points(width: f32, height: f32) -> Self {
@@ -396,7 +454,8 @@ impl Size<Dimension> {
 /// A 2-dimensional coordinate.
 ///
 /// When used in association with a [`Rect`], represents the bottom-left corner.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point<T> {
     /// The x-coordinate
     pub x: T,
@@ -409,11 +468,110 @@ impl Point<f32> {
     pub const ZERO: Point<f32> = Self { x: 0.0, y: 0.0 };
 }
 
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point2<T>> for Point<T> {
+    fn from(point: mint::Point2<T>) -> Self {
+        Point { x: point.x, y: point.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Point<T>> for mint::Point2<T> {
+    fn from(point: Point<T>) -> Self {
+        mint::Point2 { x: point.x, y: point.y }
+    }
+}
+
+/// The `width` and `height` of a [`Size`] correspond to the `x` and `y` components of the vector
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector2<T>> for Size<T> {
+    fn from(vector: mint::Vector2<T>) -> Self {
+        Size { width: vector.x, height: vector.y }
+    }
+}
+
+/// The `width` and `height` of a [`Size`] correspond to the `x` and `y` components of the vector
+#[cfg(feature = "mint")]
+impl<T> From<Size<T>> for mint::Vector2<T> {
+    fn from(size: Size<T>) -> Self {
+        mint::Vector2 { x: size.width, y: size.height }
+    }
+}
+
 /// Generic struct which holds a "min" value and a "max" value
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MinMax<Min, Max> {
     /// The value representing the minimum
     pub min: Min,
     /// The value representing the maximum
     pub max: Max,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Rect, Size};
+    use crate::style::{Dimension, Style};
+    use crate::style_helpers::{auto, zero};
+
+    #[test]
+    fn named_constants_match_helpers() {
+        let style = Style { size: Size::AUTO, min_size: Size::AUTO, max_size: Size::AUTO, ..Default::default() };
+        assert_eq!(style, Style::default());
+
+        assert_eq!(Size::<Dimension>::AUTO, auto());
+        assert_eq!(Rect::<Dimension>::AUTO, auto());
+        assert_eq!(Size::<f32>::ZERO, zero());
+        assert_eq!(Rect::<f32>::ZERO, zero());
+        assert_eq!(Size::<Option<f32>>::NONE, Size { width: None, height: None });
+    }
+
+    #[test]
+    fn clamp_size_between_min_and_max() {
+        let size = Size { width: 50.0, height: 50.0 };
+        assert_eq!(size.clamp(Size::new(60.0, 10.0), Size::new(100.0, 40.0)), Size { width: 60.0, height: 40.0 });
+        assert_eq!(size.clamp(Size::NONE, Size::NONE), size);
+        assert_eq!(
+            size.clamp(Size { width: None, height: Some(70.0) }, Size { width: Some(20.0), height: None }),
+            Size { width: 20.0, height: 70.0 }
+        );
+    }
+
+    #[test]
+    fn clamp_size_min_overrides_max() {
+        let size = Size { width: 50.0, height: 50.0 };
+        assert_eq!(size.clamp(Size::new(100.0, 100.0), Size::new(20.0, 20.0)), Size { width: 100.0, height: 100.0 });
+    }
+
+    #[test]
+    fn aspect_ratio_transfers_clamped_size() {
+        let height_only = Size { width: None, height: Some(10.0) };
+        assert_eq!(height_only.maybe_apply_aspect_ratio(Some(2.0), Size::NONE, Size::NONE), Size::new(20.0, 10.0));
+        // The known axis is clamped before its size is transferred
+        let min_height = Size { width: None, height: Some(25.0) };
+        assert_eq!(height_only.maybe_apply_aspect_ratio(Some(2.0), min_height, Size::NONE), Size::new(50.0, 25.0));
+        // The transferred size is clamped by the other axis's min and max
+        let min_width = Size { width: Some(40.0), height: None };
+        assert_eq!(height_only.maybe_apply_aspect_ratio(Some(2.0), min_width, Size::NONE), Size::new(40.0, 10.0));
+        let width_only = Size { width: Some(100.0), height: None };
+        let max_height = Size { width: None, height: Some(30.0) };
+        assert_eq!(width_only.maybe_apply_aspect_ratio(Some(2.0), Size::NONE, max_height), Size::new(100.0, 30.0));
+        assert_eq!(width_only.maybe_apply_aspect_ratio(None, Size::NONE, max_height), width_only);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_round_trip() {
+        use super::Point;
+
+        let point = Point { x: 1.0f32, y: 2.0 };
+        let mint_point: mint::Point2<f32> = point.into();
+        assert_eq!((mint_point.x, mint_point.y), (1.0, 2.0));
+        assert_eq!(Point::from(mint_point), point);
+
+        let size = Size { width: 3.0f32, height: 4.0 };
+        let mint_vector: mint::Vector2<f32> = size.into();
+        assert_eq!((mint_vector.x, mint_vector.y), (3.0, 4.0));
+        assert_eq!(Size::from(mint_vector), size);
+    }
+}
@@ -1,7 +1,12 @@
 //! Final and cached data structures that represent the high-level UI layout
 
 use crate::geometry::{Point, Size};
+use crate::node::Node;
 use crate::style::AvailableSpace;
+#[cfg(feature = "grid")]
+use crate::style::{LengthPercentage, TrackSizingFunction};
+#[cfg(feature = "grid")]
+use crate::sys::{GridTrackVec, Vec};
 
 /// Whether we are performing a full layout, or we merely need to size the node
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -24,16 +29,23 @@ pub enum SizingMode {
 
 /// The final result of a layout algorithm for a single [`Node`](crate::node::Node).
 #[derive(Copy, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Layout {
     /// The relative ordering of the node
     ///
     /// Nodes with a higher order should be rendered on top of those with a lower order.
-    /// This is effectively a topological sort of each tree.
+    /// This is the index of the node among its siblings, so it reflects document order and
+    /// is only meaningful when compared with the order of the node's siblings.
     pub order: u32,
     /// The width and height of the node
     pub size: Size<f32>,
     /// The bottom-left corner of the node
     pub location: Point<f32>,
+    /// The size of the area covered by the node and its descendants, measured from the node's top-left corner
+    ///
+    /// This is never smaller than [`Layout::size`], and is larger when the node's content overflows it. The content of a
+    /// child is clipped to it in each axis whose `overflow` is not [`Overflow::Visible`](crate::style::Overflow::Visible).
+    pub scroll_size: Size<f32>,
 }
 
 impl Layout {
@@ -44,7 +56,7 @@ impl Layout {
     /// This means it should be rendered below all other [`Layout`]s.
     #[must_use]
     pub const fn new() -> Self {
-        Self { order: 0, size: Size::zero(), location: Point::ZERO }
+        Self { order: 0, size: Size::zero(), location: Point::ZERO, scroll_size: Size::zero() }
     }
 
     /// Creates a new zero-[`Layout`] with the supplied `order` value.
@@ -53,10 +65,54 @@ impl Layout {
     /// The Zero-layout has size and location set to ZERO.
     #[must_use]
     pub const fn with_order(order: u32) -> Self {
-        Self { order, size: Size::zero(), location: Point::ZERO }
+        Self { order, size: Size::zero(), location: Point::ZERO, scroll_size: Size::zero() }
+    }
+
+    /// The relative paint order of the node among its siblings, as stored in the `order` field
+    #[must_use]
+    pub const fn order(&self) -> u32 {
+        self.order
     }
 }
 
+/// A non-fatal problem encountered while computing a layout
+///
+/// Warnings are collected by [`Taffy::compute_layout`](crate::node::Taffy::compute_layout) and can be retrieved with
+/// [`Taffy::take_layout_warnings`](crate::node::Taffy::take_layout_warnings).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LayoutWarning {
+    /// The percentage `gap` of a flex container was resolved against the container's own content-based size.
+    ///
+    /// The gap is first treated as zero in order to size the container, and then resolved against the resulting size.
+    CyclicPercentageGap {
+        /// The container whose gap is cyclic
+        node: Node,
+    },
+    /// The percentage `size` of a flex item was resolved against a container whose size depends on its content.
+    ///
+    /// The percentage is treated as `auto`.
+    CyclicPercentageSize {
+        /// The item whose size is cyclic
+        node: Node,
+    },
+}
+
+/// The columns of a parent grid that a subgrid spans, as recorded by the parent for the subgrid to adopt
+#[cfg(feature = "grid")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubgridColumns {
+    /// The sizing functions of the spanned columns and the parent's column gap
+    ///
+    /// These are recorded while the parent's tracks are being sized, so that the subgrid can estimate its contribution
+    /// to them.
+    SizingFunctions(GridTrackVec<TrackSizingFunction>, LengthPercentage),
+    /// The start and end edge of each spanned column, relative to the start of the first one
+    ///
+    /// These are recorded once the parent's tracks have been sized and aligned, so that the subgrid's column lines
+    /// line up with the parent's.
+    Edges(Vec<f32>),
+}
+
 /// Cached intermediate layout results
 #[derive(Debug, Clone, Copy)]
 pub struct Cache {
@@ -19,6 +19,7 @@ extern crate alloc;
 extern crate serde;
 
 pub mod axis;
+#[cfg(feature = "std")]
 #[doc(hidden)]
 pub mod debug;
 pub mod error;
@@ -30,6 +31,7 @@ pub mod prelude;
 pub mod style;
 pub mod style_helpers;
 pub mod tree;
+pub mod util;
 
 #[cfg(feature = "random")]
 pub mod randomizable;
@@ -39,5 +41,5 @@ mod data;
 mod resolve;
 mod sys;
 
-pub use crate::compute::compute_layout;
+pub use crate::compute::{compute_layout, compute_layout_in, compute_layout_with_callback};
 pub use crate::node::Taffy;
@@ -1,20 +1,44 @@
 //! UI [`Node`] types and related data structures.
 //!
 //! Layouts are composed of multiple nodes, which live in a tree-like data structure.
-use slotmap::{DefaultKey, SecondaryMap, SlotMap, SparseSecondaryMap};
+use core::fmt;
 
-/// A node in a layout.
-pub type Node = slotmap::DefaultKey;
+use slotmap::{Key, SecondaryMap, SlotMap, SparseSecondaryMap};
+
+slotmap::new_key_type! {
+    /// A node in a layout.
+    ///
+    /// Nodes are ordered and hashed by their slot index and version, so they can be used as keys in both
+    /// `BTreeMap` and `HashMap`.
+    pub struct Node;
+}
+
+impl fmt::Display for Node {
+    /// Formats the node as `{index}v{version}`, or `null` for the default node
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_null() {
+            return f.write_str("null");
+        }
+        let ffi = self.data().as_ffi();
+        write!(f, "{}v{}", ffi as u32, ffi >> 32)
+    }
+}
 
 use crate::error::{TaffyError, TaffyResult};
-use crate::geometry::Size;
-use crate::layout::{Cache, Layout};
+use crate::geometry::{Point, Size};
+#[cfg(feature = "grid")]
+use crate::layout::SubgridColumns;
+use crate::layout::{Cache, Layout, LayoutWarning};
 use crate::prelude::LayoutTree;
-use crate::style::{AvailableSpace, Style};
+use crate::prelude::{TaffyMaxContent, TaffyMinContent};
+use crate::style::{AvailableSpace, Display, Overflow, Style, TextAlign};
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::sys::Box;
 use crate::sys::{new_vec_with_capacity, ChildrenVec, Vec};
-use crate::{data::NodeData, error};
+use crate::{
+    data::{NodeData, CACHE_SIZE},
+    error,
+};
 
 /// A function type that can be used in a [`MeasureFunc`]
 ///
@@ -22,6 +46,18 @@ use crate::{data::NodeData, error};
 pub trait Measurable: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32> {}
 impl<F: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>> Measurable for F {}
 
+/// A function type that can be used in a [`MeasureFunc::BoxedWithMaxLines`]
+///
+/// This is a [`Measurable`] that is additionally passed the [`max_lines`](crate::style::Style::max_lines) style of the node.
+pub trait MeasurableWithMaxLines:
+    Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>, Option<u32>) -> Size<f32>
+{
+}
+impl<F: Send + Sync + Fn(Size<Option<f32>>, Size<AvailableSpace>, Option<u32>) -> Size<f32>> MeasurableWithMaxLines
+    for F
+{
+}
+
 /// A function that can be used to compute the intrinsic size of a node
 pub enum MeasureFunc {
     /// Stores an unboxed function
@@ -30,12 +66,24 @@ pub enum MeasureFunc {
     /// Stores a boxed function
     #[cfg(any(feature = "std", feature = "alloc"))]
     Boxed(Box<dyn Measurable>),
+
+    /// Stores an unboxed function that is also passed the [`max_lines`](crate::style::Style::max_lines) style of the node
+    #[allow(clippy::type_complexity)]
+    RawWithMaxLines(fn(Size<Option<f32>>, Size<AvailableSpace>, Option<u32>) -> Size<f32>),
+
+    /// Stores a boxed function that is also passed the [`max_lines`](crate::style::Style::max_lines) style of the node
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    BoxedWithMaxLines(Box<dyn MeasurableWithMaxLines>),
 }
 
 /// A tree of UI [`Nodes`](`Node`), suitable for UI layout
-pub struct Taffy {
+///
+/// `CACHE` is the number of layout results cached for each node. The default of 5 covers the distinct kinds of query
+/// that a node is usually sized under. Larger caches additionally keep results that would otherwise be overwritten by a
+/// later query of the same kind, which helps workloads that repeat many different queries. Smaller caches use less memory.
+pub struct Taffy<const CACHE: usize = CACHE_SIZE> {
     /// The [`NodeData`] for each node stored in this tree
-    pub(crate) nodes: SlotMap<Node, NodeData>,
+    pub(crate) nodes: SlotMap<Node, NodeData<CACHE>>,
 
     /// The mapping from the Size<AvailableSpace> (in real units) to Size<f32> (in points) for this node
     pub(crate) measure_funcs: SparseSecondaryMap<Node, MeasureFunc>,
@@ -49,16 +97,40 @@ pub struct Taffy {
     ///
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     pub(crate) parents: SecondaryMap<Node, Option<Node>>,
+
+    /// Warnings recorded during layout that have not yet been taken
+    pub(crate) warnings: Vec<LayoutWarning>,
+
+    /// The largest difference between two definite available spaces for which a cached result is reused
+    pub(crate) cache_epsilon: f32,
+
+    /// Whether cached sizes are shared between identical sibling subtrees
+    pub(crate) shared_cache: bool,
+
+    /// The index of the flex line that each flex item was placed on
+    pub(crate) flex_lines: SparseSecondaryMap<Node, usize>,
+
+    /// The layout of each node as it was before being rounded
+    #[cfg(feature = "test-helpers")]
+    pub(crate) unrounded_layouts: SparseSecondaryMap<Node, Layout>,
+
+    /// The track edges of each grid container, as `(columns, rows)`
+    #[cfg(feature = "grid")]
+    pub(crate) grid_lines: SparseSecondaryMap<Node, (Vec<f32>, Vec<f32>)>,
+
+    /// The columns of the parent grid that each subgrid spans
+    #[cfg(feature = "grid")]
+    pub(crate) subgrid_columns: SparseSecondaryMap<Node, SubgridColumns>,
 }
 
-impl Default for Taffy {
+impl<const CACHE: usize> Default for Taffy<CACHE> {
     fn default() -> Self {
-        Taffy::new()
+        Self::with_capacity_and_cache(16)
     }
 }
 
-impl LayoutTree for Taffy {
-    type ChildIter<'a> = std::slice::Iter<'a, DefaultKey>;
+impl<const CACHE: usize> LayoutTree for Taffy<CACHE> {
+    type ChildIter<'a> = core::slice::Iter<'a, Node>;
 
     fn children(&self, node: Node) -> Self::ChildIter<'_> {
         self.children[node].iter()
@@ -98,12 +170,29 @@ impl LayoutTree for Taffy {
         node: Node,
         known_dimensions: Size<Option<f32>>,
         available_space: Size<AvailableSpace>,
+    ) -> Size<f32> {
+        self.measure_node_with_max_lines(node, known_dimensions, available_space, self.nodes[node].style.max_lines)
+    }
+
+    fn measure_node_with_max_lines(
+        &self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        max_lines: Option<u32>,
     ) -> Size<f32> {
         match &self.measure_funcs[node] {
             MeasureFunc::Raw(measure) => measure(known_dimensions, available_space),
 
             #[cfg(any(feature = "std", feature = "alloc"))]
             MeasureFunc::Boxed(measure) => (measure as &dyn Fn(_, _) -> _)(known_dimensions, available_space),
+
+            MeasureFunc::RawWithMaxLines(measure) => measure(known_dimensions, available_space, max_lines),
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            MeasureFunc::BoxedWithMaxLines(measure) => {
+                (measure as &dyn Fn(_, _, _) -> _)(known_dimensions, available_space, max_lines)
+            }
         }
     }
 
@@ -111,16 +200,81 @@ impl LayoutTree for Taffy {
         self.nodes[node].needs_measure && self.measure_funcs.get(node).is_some()
     }
 
+    fn cache_size(&self) -> usize {
+        CACHE
+    }
+
+    fn cache_epsilon(&self) -> f32 {
+        self.cache_epsilon
+    }
+
     fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache> {
         &mut self.nodes[node].size_cache[index]
     }
 
+    fn shared_cache_peer(&self, node: Node) -> Option<Node> {
+        if !self.shared_cache {
+            return None;
+        }
+
+        let parent = self.parents.get(node).copied().flatten()?;
+        let siblings = &self.children[parent];
+        let index = siblings.iter().position(|sibling| *sibling == node)?;
+
+        // An identical previous sibling
+        if let Some(previous) = index.checked_sub(1).map(|previous| siblings[previous]) {
+            if self.subtrees_are_identical(previous, node) {
+                return Some(previous);
+            }
+        }
+
+        // The corresponding node in the subtree that the parent shares its cache with
+        let parent_peer = self.shared_cache_peer(parent)?;
+        self.children[parent_peer].get(index).copied()
+    }
+
+    #[cfg(feature = "test-helpers")]
+    fn record_unrounded_layout(&mut self, node: Node, layout: Layout) {
+        self.unrounded_layouts.insert(node, layout);
+    }
+
+    fn record_flex_line(&mut self, node: Node, line: Option<usize>) {
+        match line {
+            Some(line) => {
+                self.flex_lines.insert(node, line);
+            }
+            None => {
+                self.flex_lines.remove(node);
+            }
+        }
+    }
+
+    #[cfg(feature = "grid")]
+    fn record_grid_lines(&mut self, node: Node, columns: Vec<f32>, rows: Vec<f32>) {
+        self.grid_lines.insert(node, (columns, rows));
+    }
+
+    #[cfg(feature = "grid")]
+    fn record_subgrid_columns(&mut self, node: Node, columns: SubgridColumns) {
+        self.subgrid_columns.insert(node, columns);
+    }
+
+    #[cfg(feature = "grid")]
+    fn subgrid_columns(&self, node: Node) -> Option<&SubgridColumns> {
+        self.subgrid_columns.get(node)
+    }
+
+    fn record_warning(&mut self, warning: LayoutWarning) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
     fn child(&self, node: Node, id: usize) -> Node {
         self.children[node][id]
     }
 }
 
-#[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
 impl Taffy {
     /// Creates a new [`Taffy`]
     ///
@@ -133,16 +287,82 @@ impl Taffy {
     /// Creates a new [`Taffy`] that can store `capacity` nodes before reallocation
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_cache(capacity)
+    }
+}
+
+#[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
+impl<const CACHE: usize> Taffy<CACHE> {
+    /// Creates a new [`Taffy`] that caches `CACHE` layout results for each node, and can store `capacity` nodes before
+    /// reallocation
+    ///
+    /// ```
+    /// # use taffy::Taffy;
+    /// let taffy: Taffy<7> = Taffy::with_capacity_and_cache(16);
+    /// ```
+    #[must_use]
+    pub fn with_capacity_and_cache(capacity: usize) -> Self {
         Self {
             // TODO: make this method const upstream,
             // so constructors here can be const
-            nodes: SlotMap::with_capacity(capacity),
+            nodes: SlotMap::with_capacity_and_key(capacity),
             children: SecondaryMap::with_capacity(capacity),
             parents: SecondaryMap::with_capacity(capacity),
             measure_funcs: SparseSecondaryMap::with_capacity(capacity),
+            warnings: Vec::new(),
+            cache_epsilon: f32::EPSILON,
+            shared_cache: false,
+            flex_lines: SparseSecondaryMap::new(),
+            #[cfg(feature = "test-helpers")]
+            unrounded_layouts: SparseSecondaryMap::new(),
+            #[cfg(feature = "grid")]
+            grid_lines: SparseSecondaryMap::new(),
+            #[cfg(feature = "grid")]
+            subgrid_columns: SparseSecondaryMap::new(),
         }
     }
 
+    /// Sets the largest difference between two definite available spaces (or two known dimensions) for which a cached
+    /// layout result is reused
+    ///
+    /// Defaults to `f32::EPSILON`. Layouts that are already cached are not invalidated by changing this value.
+    pub fn set_cache_epsilon(&mut self, epsilon: f32) {
+        self.cache_epsilon = epsilon;
+    }
+
+    /// Allows nodes to reuse the cached sizes of an identical previous sibling (or of the corresponding node within an
+    /// identical previous sibling subtree)
+    ///
+    /// This speeds up layout of long lists of identical rows. Two subtrees are identical if every pair of corresponding
+    /// nodes has equal styles and the same number of children. Nodes with a boxed measure function are never shared as
+    /// it may capture per-node state (such as text), and nodes with an unboxed measure function are only shared with
+    /// nodes that use the same function, which is assumed to be pure.
+    ///
+    /// Only sizes are shared: every node is still laid out individually. Finding an identical sibling costs time
+    /// proportional to the size of the subtrees being compared, so this may slow down layout of trees which do not
+    /// contain identical siblings.
+    pub fn enable_shared_cache(&mut self) {
+        self.shared_cache = true;
+    }
+
+    /// Whether the subtrees rooted at `a` and `b` have equal styles, measure functions and structure
+    fn subtrees_are_identical(&self, a: Node, b: Node) -> bool {
+        let measure_funcs_match = match (self.needs_measure(a), self.needs_measure(b)) {
+            (false, false) => true,
+            (true, true) => match (&self.measure_funcs[a], &self.measure_funcs[b]) {
+                (MeasureFunc::Raw(a), MeasureFunc::Raw(b)) => *a as usize == *b as usize,
+                (MeasureFunc::RawWithMaxLines(a), MeasureFunc::RawWithMaxLines(b)) => *a as usize == *b as usize,
+                _ => false,
+            },
+            _ => false,
+        };
+
+        measure_funcs_match
+            && self.nodes[a].style == self.nodes[b].style
+            && self.children[a].len() == self.children[b].len()
+            && self.children[a].iter().zip(self.children[b].iter()).all(|(a, b)| self.subtrees_are_identical(*a, *b))
+    }
+
     /// Creates and adds a new unattached leaf node to the tree, and returns the [`NodeId`] of the new node
     pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<Node> {
         let id = self.nodes.insert(NodeData::new(layout));
@@ -189,6 +409,13 @@ impl Taffy {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.flex_lines.clear();
+        #[cfg(feature = "test-helpers")]
+        self.unrounded_layouts.clear();
+        #[cfg(feature = "grid")]
+        self.grid_lines.clear();
+        #[cfg(feature = "grid")]
+        self.subgrid_columns.clear();
     }
 
     /// Remove a specific [`Node`] from the tree
@@ -204,11 +431,21 @@ impl Taffy {
         let _ = self.children.remove(node);
         let _ = self.parents.remove(node);
         let _ = self.nodes.remove(node);
+        let _ = self.flex_lines.remove(node);
+        #[cfg(feature = "test-helpers")]
+        let _ = self.unrounded_layouts.remove(node);
+        #[cfg(feature = "grid")]
+        let _ = self.grid_lines.remove(node);
+        #[cfg(feature = "grid")]
+        let _ = self.subgrid_columns.remove(node);
 
         Ok(node)
     }
 
     /// Sets the [`MeasureFunc`] of the associated node
+    ///
+    /// If the node has children, the measure function determines the size of the node in place of its children, which
+    /// are then laid out within that size.
     pub fn set_measure(&mut self, node: Node, measure: Option<MeasureFunc>) -> TaffyResult<()> {
         if let Some(measure) = measure {
             self.nodes[node].needs_measure = true;
@@ -294,6 +531,20 @@ impl Taffy {
         Ok(old_child)
     }
 
+    /// Swaps the positions of the children at `child_index_a` and `child_index_b` of the `parent` node
+    pub fn swap_children(&mut self, parent: Node, child_index_a: usize, child_index_b: usize) -> TaffyResult<()> {
+        let child_count = self.children[parent].len();
+        for child_index in [child_index_a, child_index_b] {
+            if child_index >= child_count {
+                return Err(error::TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count });
+            }
+        }
+
+        self.children[parent].swap(child_index_a, child_index_b);
+
+        self.mark_dirty_internal(parent)
+    }
+
     /// Returns the child [`Node`] of the parent `node` at the provided `child_index`
     pub fn child_at_index(&self, parent: Node, child_index: usize) -> TaffyResult<Node> {
         let child_count = self.children[parent].len();
@@ -314,6 +565,24 @@ impl Taffy {
         Ok(self.children[parent].iter().copied().collect::<_>())
     }
 
+    /// Returns a list of all nodes in the tree that do not have a parent
+    ///
+    /// Each of these nodes is the root of an independent tree which can be laid out with [`Taffy::compute_layout`]
+    pub fn roots(&self) -> Vec<Node> {
+        self.parents.iter().filter(|(_, parent)| parent.is_none()).map(|(node, _)| node).collect::<_>()
+    }
+
+    /// Returns the number of ancestors of the provided `node`, so that the root of a tree has a depth of zero
+    pub fn depth(&self, node: Node) -> TaffyResult<usize> {
+        let mut parent = *self.parents.get(node).ok_or(TaffyError::InvalidInputNode(node))?;
+        let mut depth = 0;
+        while let Some(node) = parent {
+            depth += 1;
+            parent = self.parents[node];
+        }
+        Ok(depth)
+    }
+
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: Node, style: Style) -> TaffyResult<()> {
         self.nodes[node].style = style;
@@ -321,14 +590,161 @@ impl Taffy {
         Ok(())
     }
 
+    /// Modifies the [`Style`] of the provided `node` in place with `f`, then marks the node as dirty
+    ///
+    /// This avoids cloning the whole style when only a few properties change, such as when animating a single property.
+    pub fn patch_style(&mut self, node: Node, f: impl FnOnce(&mut Style)) -> TaffyResult<()> {
+        let data = self.nodes.get_mut(node).ok_or(TaffyError::InvalidInputNode(node))?;
+        f(&mut data.style);
+        self.mark_dirty_internal(node)
+    }
+
     /// Gets the [`Style`] of the provided `node`
     pub fn style(&self, node: Node) -> TaffyResult<&Style> {
         Ok(&self.nodes[node].style)
     }
 
+    /// Gets an owned copy of the [`Style`] of the provided `node`
+    ///
+    /// This is useful for inspecting a style on another thread without holding a borrow of the tree.
+    pub fn style_cloned(&self, node: Node) -> TaffyResult<Style> {
+        self.nodes.get(node).map(|data| data.style.clone()).ok_or(TaffyError::InvalidInputNode(node))
+    }
+
+    /// Gets the [`TextAlign`] of the provided `node`
+    ///
+    /// Taffy does not use this value itself, it is stored for use by measure functions and renderers.
+    pub fn text_align(&self, node: Node) -> TaffyResult<TextAlign> {
+        let node_data = self.nodes.get(node).ok_or(TaffyError::InvalidInputNode(node))?;
+        Ok(node_data.style.text_align)
+    }
+
     /// Return this node layout relative to its parent
     pub fn layout(&self, node: Node) -> TaffyResult<&Layout> {
-        Ok(&self.nodes[node].layout)
+        self.try_layout(node).ok_or(TaffyError::InvalidInputNode(node))
+    }
+
+    /// Return this node layout relative to its parent, or `None` if the node is not in the tree
+    #[inline]
+    pub fn try_layout(&self, node: Node) -> Option<&Layout> {
+        self.nodes.get(node).map(|data| &data.layout)
+    }
+
+    /// Return the size of the area covered by this node and its descendants, which exceeds the size of the node if its
+    /// content overflows it
+    ///
+    /// See [`Layout::scroll_size`].
+    pub fn scroll_size(&self, node: Node) -> TaffyResult<Size<f32>> {
+        Ok(self.layout(node)?.scroll_size)
+    }
+
+    /// Checks that rounding has not left a 1px gap or overlap between any two adjacent in-flow siblings in the subtree
+    /// rooted at `root`, returning [`TaffyError::RoundingGap`] for the first such pair that is found
+    ///
+    /// Siblings are adjacent if they are consecutive children of the same parent which share a row (or column). The
+    /// spacing between them is compared with the spacing between their layouts from before rounding, so gaps that are
+    /// specified by the styles (e.g. with `gap` or `margin`) are not reported. This is a debugging aid for renderers
+    /// that are sensitive to seams between boxes.
+    #[cfg(feature = "test-helpers")]
+    pub fn verify_no_rounding_gaps(&self, root: Node) -> TaffyResult<()> {
+        use crate::sys::round;
+
+        /// The spacing between two spans (given as `(start, length)`), which is negative if they overlap
+        fn spacing(first: (f32, f32), second: (f32, f32)) -> f32 {
+            second.0 - (first.0 + first.1)
+        }
+        /// Whether rounding has moved two spans at least 1px closer together or further apart
+        fn off_by_one(unrounded: ((f32, f32), (f32, f32)), rounded: ((f32, f32), (f32, f32))) -> bool {
+            (spacing(rounded.0, rounded.1) - round(spacing(unrounded.0, unrounded.1))).abs() >= 1.0
+        }
+        /// Whether two spans (given as `(start, length)`) overlap
+        fn overlaps(first: (f32, f32), second: (f32, f32)) -> bool {
+            first.0 < second.0 + second.1 && second.0 < first.0 + first.1
+        }
+
+        if !self.nodes.contains_key(root) {
+            return Err(TaffyError::InvalidInputNode(root));
+        }
+
+        let in_flow_children: Vec<Node> = self.children[root]
+            .iter()
+            .copied()
+            .filter(|child| {
+                let style = &self.nodes[*child].style;
+                style.display != Display::None && !style.position.is_absolutely_positioned()
+            })
+            .collect();
+        // Nodes that have not been laid out since they were added have no unrounded layout
+        let unrounded_layout =
+            |node: Node| self.unrounded_layouts.get(node).copied().unwrap_or(self.nodes[node].layout);
+        for pair in in_flow_children.windows(2) {
+            let (first, second) = (unrounded_layout(pair[0]), unrounded_layout(pair[1]));
+            let (rounded_first, rounded_second) = (self.nodes[pair[0]].layout, self.nodes[pair[1]].layout);
+            let horizontal = |layout: Layout| (layout.location.x, layout.size.width);
+            let vertical = |layout: Layout| (layout.location.y, layout.size.height);
+            let has_gap = (overlaps(vertical(first), vertical(second))
+                && off_by_one(
+                    (horizontal(first), horizontal(second)),
+                    (horizontal(rounded_first), horizontal(rounded_second)),
+                ))
+                || (overlaps(horizontal(first), horizontal(second))
+                    && off_by_one(
+                        (vertical(first), vertical(second)),
+                        (vertical(rounded_first), vertical(rounded_second)),
+                    ));
+            if has_gap {
+                return Err(TaffyError::RoundingGap { first: pair[0], second: pair[1] });
+            }
+        }
+
+        self.children[root].iter().try_for_each(|child| self.verify_no_rounding_gaps(*child))
+    }
+
+    /// Return the overflow behaviour of this node in each axis
+    ///
+    /// Together with [`Layout::size`] and [`Layout::scroll_size`], this tells a renderer whether to clip the content of
+    /// the node to its bounds (and whether to make it scrollable) in each axis.
+    pub fn overflow(&self, node: Node) -> TaffyResult<Point<Overflow>> {
+        let node_data = self.nodes.get(node).ok_or(TaffyError::InvalidInputNode(node))?;
+        Ok(node_data.style.overflow)
+    }
+
+    /// Return the index of the flex line that this node was placed on when its flex container was last laid out
+    ///
+    /// Lines are numbered in the order that they are filled, starting from zero. Returns `None` if the node is not an
+    /// in-flow child of a flex container (for example because it is absolutely positioned or hidden), or if its container
+    /// has not been laid out since it became one.
+    pub fn flex_line_of(&self, node: Node) -> TaffyResult<Option<usize>> {
+        if !self.nodes.contains_key(node) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+
+        // The children of `Display::Contents` nodes are laid out by the nearest ancestor that generates a box
+        let mut container = self.parents[node];
+        while let Some(parent) = container {
+            if self.nodes[parent].style.display != Display::Contents {
+                break;
+            }
+            container = self.parents[parent];
+        }
+        match container {
+            Some(container) if self.nodes[container].style.display == Display::Flex => {
+                Ok(self.flex_lines.get(node).copied())
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Return the edges of the column and row tracks of this grid container, relative to its content box
+    ///
+    /// Each track contributes its start and end position in turn, so gaps between tracks show up as differences between
+    /// the end of one track and the start of the next. Returns empty lists if the node has not been laid out as a grid.
+    #[cfg(feature = "grid")]
+    pub fn grid_lines(&self, node: Node) -> TaffyResult<(Vec<f32>, Vec<f32>)> {
+        if !self.nodes.contains_key(node) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+        Ok(self.grid_lines.get(node).cloned().unwrap_or_default())
     }
 
     /// Marks the layout computation of this node and its children as outdated
@@ -338,8 +754,8 @@ impl Taffy {
     /// WARNING: this will stack-overflow if the tree contains a cycle
     fn mark_dirty_internal(&mut self, node: Node) -> TaffyResult<()> {
         /// WARNING: this will stack-overflow if the tree contains a cycle
-        fn mark_dirty_recursive(
-            nodes: &mut SlotMap<Node, NodeData>,
+        fn mark_dirty_recursive<const CACHE: usize>(
+            nodes: &mut SlotMap<Node, NodeData<CACHE>>,
             parents: &SecondaryMap<Node, Option<Node>>,
             node_id: Node,
         ) {
@@ -360,10 +776,136 @@ impl Taffy {
         Ok(self.nodes[node].size_cache.iter().all(|entry| entry.is_none()))
     }
 
+    /// Resets the stored layout of this node to zero and clears its cached layout results
+    ///
+    /// The node keeps its style and children. Its ancestors are marked dirty too, so that the next call to
+    /// [`Taffy::compute_layout`] computes its layout again.
+    pub fn reset_node(&mut self, node: Node) -> TaffyResult<()> {
+        let data = self.nodes.get_mut(node).ok_or(TaffyError::InvalidInputNode(node))?;
+        data.layout = Layout::new();
+        self.mark_dirty_internal(node)
+    }
+
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// Any non-fatal problems encountered along the way can be retrieved with [`Taffy::take_layout_warnings`].
     pub fn compute_layout(&mut self, node: Node, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         crate::compute::compute_layout(self, node, available_space)
     }
+
+    /// Updates the stored layout of the provided `node` and its children, laying it out against a definite available
+    /// size in each axis that is `Some`, and under a max-content constraint in each axis that is `None`
+    pub fn compute_layout_with_options(&mut self, node: Node, size: Size<Option<f32>>) -> Result<(), TaffyError> {
+        self.compute_layout(node, size.map(AvailableSpace::from))
+    }
+
+    /// Updates the stored layouts of each of the provided root nodes and their children, in order
+    ///
+    /// This is equivalent to calling [`Taffy::compute_layout`] for each root. Layout stops at the first root that fails,
+    /// and that error is returned; the roots before it keep their updated layouts.
+    pub fn compute_layouts(&mut self, roots: &[(Node, Size<AvailableSpace>)]) -> Result<(), TaffyError> {
+        roots.iter().try_for_each(|&(node, available_space)| self.compute_layout(node, available_space))
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, resolving percentage styles of `node` against
+    /// `parent_size`
+    ///
+    /// This is useful when embedding the tree in a layout that was computed externally: [`Taffy::compute_layout`]
+    /// resolves them against the definite parts of `available_space` instead. The layout of `node` itself is always
+    /// recomputed, as cached results do not record the parent size that they were computed with.
+    pub fn compute_layout_in(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+        parent_size: Size<Option<f32>>,
+    ) -> Result<(), TaffyError> {
+        // Cached results do not take the parent size into account, so the root must be recomputed
+        self.mark_dirty_internal(node)?;
+        crate::compute::compute_layout_in(self, node, available_space, parent_size, |_, _| {})
+    }
+
+    /// Returns the min-content size of the provided `node`: the smallest size it can take without overflowing
+    ///
+    /// The node is sized as if it were the root of the tree. Stored layouts are not updated.
+    pub fn min_content_size(&mut self, node: Node) -> TaffyResult<Size<f32>> {
+        if !self.nodes.contains_key(node) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+        crate::compute::compute_size(self, node, Size::MIN_CONTENT)
+    }
+
+    /// Returns the max-content size of the provided `node`: the size it would take given infinite available space
+    ///
+    /// The node is sized as if it were the root of the tree. Stored layouts are not updated.
+    pub fn max_content_size(&mut self, node: Node) -> TaffyResult<Size<f32>> {
+        if !self.nodes.contains_key(node) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+        crate::compute::compute_size(self, node, Size::MAX_CONTENT)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, sizing `node` to fit its content but no larger than `max`
+    ///
+    /// In each axis `node` takes its max-content size, clamped to `max` but never below its min-content size (see
+    /// [`AvailableSpace::shrink_to_fit`]). This suits nodes such as tooltips that should shrink-wrap their content.
+    /// Returns the final size of `node`.
+    pub fn compute_fit(&mut self, node: Node, max: Size<f32>) -> TaffyResult<Size<f32>> {
+        let min_content = self.min_content_size(node)?;
+        let max_content = self.max_content_size(node)?;
+        let width = AvailableSpace::Definite(max.width).shrink_to_fit(min_content.width, max_content.width);
+
+        // The content height depends on the chosen width, so it is only clamped if laying out at that width overflows,
+        // in which case the height is fixed to the maximum and the content overflows it
+        let available_space = Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+        self.compute_layout(node, available_space)?;
+        if self.layout(node)?.size.height > max.height {
+            let known_dimensions = Size { width: None, height: Some(max.height) };
+            crate::compute::compute_root_layout(
+                self,
+                node,
+                known_dimensions,
+                available_space,
+                available_space.into_options(),
+                |_, _| {},
+            )?;
+        }
+
+        Ok(self.layout(node)?.size)
+    }
+
+    /// Returns the warnings recorded since the last call to this method, leaving none stored
+    ///
+    /// Each distinct warning is only recorded once, and nodes whose layout is reused from the cache do not record
+    /// their warnings again.
+    pub fn take_layout_warnings(&mut self) -> Vec<LayoutWarning> {
+        core::mem::take(&mut self.warnings)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, calling `callback` with the final layout of each node
+    ///
+    /// This is useful for copying computed layouts into another data structure (such as an ECS) without a separate traversal.
+    pub fn compute_layout_with_callback(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+        callback: impl FnMut(Node, &Layout),
+    ) -> Result<(), TaffyError> {
+        crate::compute::compute_layout_with_callback(self, node, available_space, callback)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, computing independent subtrees in parallel
+    ///
+    /// Only children of `node` whose layout cannot be affected by their parent or siblings (those with a definite size in
+    /// both axes and no percentage-based sizing or spacing styles) are computed in parallel. The result is always identical
+    /// to that of [`Taffy::compute_layout`].
+    #[cfg(feature = "rayon")]
+    pub fn compute_layout_parallel(
+        &mut self,
+        node: Node,
+        available_space: Size<AvailableSpace>,
+    ) -> Result<(), TaffyError> {
+        crate::compute::parallel::compute_layout_parallel(self, node, available_space)
+    }
 }
 
 #[cfg(test)]
@@ -371,7 +913,8 @@ mod tests {
     #![allow(clippy::bool_assert_comparison)]
 
     use super::*;
-    use crate::style::{Dimension, Display, FlexDirection};
+    use crate::geometry::Rect;
+    use crate::style::{Dimension, Display, FlexDirection, FlexWrap, Position};
     use crate::style_helpers::*;
     use crate::sys;
 
@@ -588,6 +1131,50 @@ mod tests {
         assert_eq!(taffy.child_count(node).unwrap(), 1);
         assert_eq!(taffy.children(node).unwrap()[0], child1);
     }
+    #[test]
+    fn depth() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        let inner = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+        let outer = taffy.new_with_children(Style::default(), &[inner]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[outer]).unwrap();
+
+        assert_eq!(taffy.depth(root).unwrap(), 0);
+        assert_eq!(taffy.depth(outer).unwrap(), 1);
+        assert_eq!(taffy.depth(inner).unwrap(), 2);
+        assert_eq!(taffy.depth(leaf).unwrap(), 3);
+
+        taffy.remove(leaf).unwrap();
+        assert!(matches!(taffy.depth(leaf), Err(TaffyError::InvalidInputNode(invalid)) if invalid == leaf));
+    }
+
+    #[test]
+    fn swap_children() {
+        let mut taffy = Taffy::new();
+
+        let child0 = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let child1 = taffy.new_leaf(Style { size: Size::from_points(20.0, 10.0), ..Default::default() }).unwrap();
+        let child2 = taffy.new_leaf(Style { size: Size::from_points(30.0, 10.0), ..Default::default() }).unwrap();
+
+        let node = taffy.new_with_children(Style::default(), &[child0, child1, child2]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child0).unwrap().location.x, 0.0);
+
+        taffy.swap_children(node, 0, 2).unwrap();
+        assert_eq!(taffy.children(node).unwrap(), sys::Vec::from([child2, child1, child0]));
+        assert!(taffy.dirty(node).unwrap());
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child2).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(child1).unwrap().location.x, 30.0);
+        assert_eq!(taffy.layout(child0).unwrap().location.x, 50.0);
+
+        assert!(matches!(
+            taffy.swap_children(node, 0, 3),
+            Err(TaffyError::ChildIndexOutOfBounds { child_index: 3, child_count: 3, .. })
+        ));
+    }
+
     #[test]
     fn test_child_at_index() {
         let mut taffy = Taffy::new();
@@ -629,6 +1216,22 @@ mod tests {
 
         assert!(taffy.children(child0).unwrap().is_empty());
     }
+
+    #[test]
+    fn test_roots() {
+        let mut taffy = Taffy::new();
+        let child0 = taffy.new_leaf(Style::default()).unwrap();
+        let root0 = taffy.new_with_children(Style::default(), &[child0]).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let root1 = taffy.new_with_children(Style::default(), &[child1]).unwrap();
+        let detached = taffy.new_leaf(Style::default()).unwrap();
+
+        let roots = taffy.roots();
+        assert_eq!(roots.len(), 3);
+        assert!(roots.contains(&root0));
+        assert!(roots.contains(&root1));
+        assert!(roots.contains(&detached));
+    }
     #[test]
     fn test_set_style() {
         let mut taffy = Taffy::new();
@@ -640,6 +1243,24 @@ mod tests {
         assert_eq!(taffy.style(node).unwrap().display, Display::None);
     }
     #[test]
+    fn test_patch_style() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        for frame in 1..=3 {
+            taffy.patch_style(child, |style| style.size.width = Dimension::Points(10.0 + frame as f32)).unwrap();
+            taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+            assert_eq!(taffy.layout(child).unwrap().size.width, 10.0 + frame as f32);
+            assert_eq!(taffy.layout(node).unwrap().size.width, 10.0 + frame as f32);
+        }
+
+        taffy.remove(child).unwrap();
+        assert!(
+            matches!(taffy.patch_style(child, |_| {}), Err(TaffyError::InvalidInputNode(invalid)) if invalid == child)
+        );
+    }
+    #[test]
     fn test_style() {
         let mut taffy = Taffy::new();
 
@@ -651,6 +1272,126 @@ mod tests {
         assert!(res.is_ok());
         assert!(res.unwrap() == &style);
     }
+    #[test]
+    fn test_style_cloned() {
+        let mut taffy = Taffy::new();
+
+        let style = Style { display: Display::None, flex_direction: FlexDirection::RowReverse, ..Default::default() };
+        let node = taffy.new_leaf(style.clone()).unwrap();
+
+        let cloned = taffy.style_cloned(node).unwrap();
+        assert_eq!(cloned, style);
+        assert_eq!(&cloned, taffy.style(node).unwrap());
+
+        taffy.remove(node).unwrap();
+        assert!(matches!(taffy.style_cloned(node), Err(TaffyError::InvalidInputNode(invalid)) if invalid == node));
+    }
+    #[test]
+    fn test_text_align() {
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf(Style { text_align: TextAlign::Center, ..Default::default() }).unwrap();
+        assert_eq!(taffy.text_align(node).unwrap(), TextAlign::Center);
+
+        taffy.set_style(node, Style { text_align: TextAlign::Justify, ..Default::default() }).unwrap();
+        assert_eq!(taffy.text_align(node).unwrap(), TextAlign::Justify);
+
+        taffy.remove(node).unwrap();
+        assert!(matches!(taffy.text_align(node), Err(TaffyError::InvalidInputNode(invalid)) if invalid == node));
+    }
+
+    #[test]
+    fn cyclic_percentages_record_warnings() {
+        use crate::style::LengthPercentage;
+
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Points(40.0), height: Dimension::Percent(0.5) },
+                ..Default::default()
+            })
+            .unwrap();
+        let sibling = taffy.new_leaf(Style { size: Size::from_points(40.0, 10.0), ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style { gap: Size { width: LengthPercentage::Percent(0.1), height: zero() }, ..Default::default() },
+                &[child, sibling],
+            )
+            .unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        let warnings = taffy.take_layout_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.contains(&LayoutWarning::CyclicPercentageGap { node: container }));
+        assert!(warnings.contains(&LayoutWarning::CyclicPercentageSize { node: child }));
+        assert!(taffy.take_layout_warnings().is_empty());
+
+        let layout = taffy.layout(container).unwrap();
+        assert!(layout.size.width.is_finite() && layout.size.height.is_finite());
+        assert_eq!(taffy.layout(child).unwrap().size.height, 0.0);
+    }
+
+    #[test]
+    fn reset_node_zeroes_layout_until_next_compute() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(10.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 20.0 });
+
+        taffy.reset_node(child).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size::zero());
+        assert!(taffy.dirty(child).unwrap());
+        assert_eq!(taffy.style(child).unwrap().size, Size::from_points(10.0, 20.0));
+        assert_eq!(taffy.children(root).unwrap().as_slice(), &[child]);
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 20.0 });
+    }
+
+    #[test]
+    fn scroll_size_includes_overflowing_children() {
+        let mut taffy = Taffy::new();
+        let child_style = Style { size: Size::from_points(80.0, 120.0), flex_shrink: 0.0, ..Default::default() };
+        let child0 = taffy.new_leaf(child_style.clone()).unwrap();
+        let child1 = taffy.new_leaf(child_style).unwrap();
+        let container = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child0, child1])
+            .unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 100.0 });
+        assert_eq!(taffy.scroll_size(container).unwrap(), Size { width: 160.0, height: 120.0 });
+        assert_eq!(taffy.scroll_size(child0).unwrap(), Size { width: 80.0, height: 120.0 });
+    }
+
+    #[test]
+    fn compute_layout_in_resolves_root_percentages_against_parent_size() {
+        use crate::geometry::{Point, Rect};
+        use crate::style::LengthPercentage;
+
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+        let padding = LengthPercentage::Percent(0.1);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size::from_points(100.0, 100.0),
+                    padding: Rect { left: padding, right: zero(), top: padding, bottom: zero() },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let available_space = Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::Definite(300.0) };
+
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 20.0, y: 20.0 });
+
+        // Padding percentages resolve against the width of the parent in both axes
+        taffy.compute_layout_in(root, available_space, Size { width: Some(500.0), height: None }).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 50.0, y: 50.0 });
+    }
+
     #[test]
     fn test_layout() {
         let mut taffy = Taffy::new();
@@ -661,6 +1402,19 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_try_layout() {
+        let mut taffy = Taffy::new();
+        let node = taffy.new_leaf(Style { size: Size::from_points(10.0, 20.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.try_layout(node).unwrap().size, taffy.layout(node).unwrap().size);
+
+        taffy.remove(node).unwrap();
+        assert!(taffy.try_layout(node).is_none());
+        assert!(matches!(taffy.layout(node), Err(TaffyError::InvalidInputNode(invalid)) if invalid == node));
+    }
+
     #[test]
     fn test_mark_dirty() {
         let mut taffy = Taffy::new();
@@ -702,6 +1456,241 @@ mod tests {
         assert!(layout_result.is_ok());
     }
 
+    #[test]
+    fn compute_layout_with_callback_visits_every_node_once() {
+        let mut taffy = Taffy::new();
+        let grandchild = taffy.new_leaf(Style { size: Size::from_points(10.5, 20.5), ..Default::default() }).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+        let child1 = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let node = taffy
+            .new_with_children(Style { size: Size::from_points(100.0, 100.0), ..Default::default() }, &[child0, child1])
+            .unwrap();
+
+        let mut visited: sys::Vec<(Node, Layout)> = sys::Vec::new();
+        taffy
+            .compute_layout_with_callback(node, Size::MAX_CONTENT, |node, layout| visited.push((node, *layout)))
+            .unwrap();
+
+        assert_eq!(visited.len(), 4);
+        for node in [node, child0, grandchild, child1] {
+            let layouts: sys::Vec<&Layout> = visited.iter().filter(|(n, _)| *n == node).map(|(_, l)| l).collect();
+            assert_eq!(layouts.len(), 1);
+            assert_eq!(layouts[0].size, taffy.layout(node).unwrap().size);
+            assert_eq!(layouts[0].location, taffy.layout(node).unwrap().location);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn grid_lines_include_gaps_and_exclude_padding() {
+        let mut taffy = Taffy::new();
+        let children: Vec<Node> = (0..6).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: crate::style::GridTemplate::Tracks(vec![points(50.0); 3]),
+                    grid_template_rows: vec![points(30.0); 2],
+                    gap: points(10.0),
+                    padding: points(5.0),
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+        let leaf = taffy.new_leaf(Style::default()).unwrap();
+        taffy.compute_layout(grid, Size::MAX_CONTENT).unwrap();
+
+        let (columns, rows) = taffy.grid_lines(grid).unwrap();
+        assert_eq!(columns, vec![0.0, 50.0, 60.0, 110.0, 120.0, 170.0]);
+        assert_eq!(rows, vec![0.0, 30.0, 40.0, 70.0]);
+        assert_eq!(taffy.grid_lines(leaf).unwrap(), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn flex_line_of_reports_the_line_of_each_item() {
+        let mut taffy = Taffy::new();
+        let item_style = Style { size: Size { width: points(40.0), height: points(10.0) }, ..Default::default() };
+        let items: Vec<Node> = (0..5).map(|_| taffy.new_leaf(item_style.clone()).unwrap()).collect();
+        let absolute = taffy.new_leaf(Style { position: Position::Absolute, ..item_style.clone() }).unwrap();
+        let hidden = taffy.new_leaf(Style { display: Display::None, ..item_style }).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    size: Size { width: points(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[items[0], items[1], absolute, items[2], hidden, items[3], items[4]],
+            )
+            .unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        let lines: Vec<Option<usize>> = items.iter().map(|item| taffy.flex_line_of(*item).unwrap()).collect();
+        assert_eq!(lines, vec![Some(0), Some(0), Some(1), Some(1), Some(2)]);
+        assert_eq!(taffy.flex_line_of(absolute).unwrap(), None);
+        assert_eq!(taffy.flex_line_of(hidden).unwrap(), None);
+        assert_eq!(taffy.flex_line_of(container).unwrap(), None);
+
+        // Items that leave the container are no longer on any of its lines
+        taffy.remove_child(container, items[4]).unwrap();
+        assert_eq!(taffy.flex_line_of(items[4]).unwrap(), None);
+    }
+
+    #[test]
+    fn node_can_key_a_btree_map_and_be_displayed() {
+        use std::collections::BTreeMap;
+
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+
+        let mut names = BTreeMap::new();
+        names.insert(second, "second");
+        names.insert(first, "first");
+        assert_eq!(names.values().copied().collect::<Vec<_>>(), vec!["first", "second"]);
+
+        assert_eq!(format!("{first}"), "1v1");
+        assert_eq!(format!("{second}"), "2v1");
+        assert_eq!(format!("{}", Node::default()), "null");
+
+        taffy.remove(first).unwrap();
+        let reused = taffy.new_leaf(Style::default()).unwrap();
+        assert_eq!(format!("{reused}"), "1v3");
+    }
+
+    #[test]
+    fn compute_layouts_lays_out_each_root() {
+        let mut taffy = Taffy::new();
+        let first = taffy.new_leaf(Style { size: points(10.0), ..Default::default() }).unwrap();
+        let second = taffy.new_leaf(Style { size: Size::from_percent(0.5, 0.5), ..Default::default() }).unwrap();
+
+        taffy
+            .compute_layouts(&[
+                (first, Size::MAX_CONTENT),
+                (second, Size { width: AvailableSpace::Definite(40.0), height: AvailableSpace::Definite(20.0) }),
+            ])
+            .unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 10.0, height: 10.0 });
+        assert_eq!(taffy.layout(second).unwrap().size, Size { width: 20.0, height: 10.0 });
+
+        #[cfg(any(debug_assertions, feature = "validate"))]
+        {
+            taffy.set_style(second, Style { flex_grow: f32::NAN, ..Default::default() }).unwrap();
+            let result = taffy.compute_layouts(&[(first, Size::MAX_CONTENT), (second, Size::MAX_CONTENT)]);
+            assert!(matches!(result, Err(TaffyError::InvalidStyle { node }) if node == second));
+        }
+    }
+
+    #[test]
+    fn overflow_is_reported_per_axis() {
+        let mut taffy = Taffy::new();
+        let node = taffy
+            .new_leaf(Style {
+                overflow: Point { x: Overflow::Scroll, y: Overflow::Hidden },
+                size: points(10.0),
+                ..Default::default()
+            })
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.overflow(node).unwrap(), Point { x: Overflow::Scroll, y: Overflow::Hidden });
+        taffy.remove(node).unwrap();
+        assert!(taffy.overflow(node).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "test-helpers")]
+    fn verify_no_rounding_gaps_detects_seams() {
+        let mut taffy = Taffy::new();
+        let children: Vec<Node> =
+            (0..3).map(|_| taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap()).collect();
+        let root = taffy.new_with_children(Style { size: points(100.0), ..Default::default() }, &children).unwrap();
+
+        // Each child is 33.33px wide, so the last one starts at 66.67px, which rounds to 67px rather than 66px
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(children[2]).unwrap().location.x, 67.0);
+        assert!(matches!(
+            taffy.verify_no_rounding_gaps(root),
+            Err(TaffyError::RoundingGap { first, second }) if first == children[1] && second == children[2]
+        ));
+
+        taffy.set_style(root, Style { size: points(99.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.verify_no_rounding_gaps(root).is_ok());
+
+        // 1px gaps specified by the `gap` or `margin` styles are not caused by rounding
+        for child in &children {
+            taffy.set_style(*child, Style { size: points(10.0), ..Default::default() }).unwrap();
+        }
+        taffy.set_style(root, Style { gap: points(1.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(children[2]).unwrap().location.x, 22.0);
+        assert!(taffy.verify_no_rounding_gaps(root).is_ok());
+
+        let margin = Rect { left: points(1.0), ..Rect::zero() };
+        taffy.set_style(children[1], Style { size: points(10.0), margin, ..Default::default() }).unwrap();
+        taffy.set_style(root, Style::default()).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(children[1]).unwrap().location.x, 11.0);
+        assert!(taffy.verify_no_rounding_gaps(root).is_ok());
+    }
+
+    #[test]
+    fn compute_layout_with_options_matches_available_space() {
+        let mut taffy = Taffy::new();
+        // Fills the available width, up to 200px
+        let child = taffy
+            .new_leaf_with_measure(
+                Style::default(),
+                MeasureFunc::Raw(|known_dimensions, available_space| Size {
+                    width: known_dimensions
+                        .width
+                        .unwrap_or_else(|| available_space.width.definite_or(200.0).min(200.0)),
+                    height: 30.0,
+                }),
+            )
+            .unwrap();
+        let node = taffy.new_with_children(Style { padding: points(5.0), ..Default::default() }, &[child]).unwrap();
+
+        taffy.compute_layout_with_options(node, Size { width: Some(100.0), height: None }).unwrap();
+        let from_options = taffy.layout(node).unwrap().size;
+        taffy
+            .compute_layout(node, Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::MaxContent })
+            .unwrap();
+
+        assert_eq!(taffy.layout(node).unwrap().size, from_options);
+
+        taffy.compute_layout_with_options(node, Size::NONE).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 210.0, height: 40.0 });
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 210.0, height: 40.0 });
+    }
+
+    #[test]
+    fn display_none_subtree_is_laid_out_once_shown() {
+        let mut taffy = Taffy::new();
+        let leaf = taffy.new_leaf(Style { size: points(10.0), ..Default::default() }).unwrap();
+        let grandchild =
+            taffy.new_with_children(Style { padding: points(2.0), ..Default::default() }, &[leaf]).unwrap();
+        let child =
+            taffy.new_with_children(Style { padding: points(5.0), ..Default::default() }, &[grandchild]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+
+        for display in [Display::Flex, Display::None, Display::Flex] {
+            taffy.set_style(child, Style { display, padding: points(5.0), ..Default::default() }).unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        }
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 24.0, height: 24.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 24.0, height: 24.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 14.0, height: 14.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().location, Point { x: 5.0, y: 5.0 });
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 10.0, height: 10.0 });
+        assert_eq!(taffy.layout(leaf).unwrap().location, Point { x: 2.0, y: 2.0 });
+    }
+
     #[test]
     fn measure_func_is_send_and_sync() {
         fn is_send_and_sync<T: Send + Sync>() {}
@@ -6,19 +6,20 @@ pub use crate::{
     layout::Layout,
     node::{Node, Taffy},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, FlexDirection, FlexWrap,
-        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
+        AlignContent, AlignItems, AlignSelf, AvailableSpace, ContentVisibility, Dimension, Display, FlexDirection,
+        FlexWrap, JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Overflow,
+        Position, Style, TextAlign,
     },
     style_helpers::{
         auto, fit_content, flex, max_content, min_content, minmax, percent, points, zero, FromFlex, FromPercent,
-        FromPoints, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+        FromPoints, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyUnits, TaffyZero,
     },
     tree::LayoutTree,
 };
 
 #[cfg(feature = "grid")]
 pub use crate::style::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplate, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
     NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 #[cfg(feature = "grid")]
@@ -49,7 +49,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
 impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
     /// Converts the given [`Dimension`] into a concrete value of points
     ///
-    /// Can return `None`
+    /// Can return `None`. In particular, percentages resolve to `None` if the context is indefinite, which means that a
+    /// percentage `min_size` against an indefinite parent size acts as zero and a percentage `max_size` acts as `none`.
     fn maybe_resolve(self, context: Option<f32>) -> Option<f32> {
         match self {
             Dimension::Points(points) => Some(points),
@@ -283,7 +284,7 @@ mod tests {
             #[case] context: Size<Option<f32>>,
             #[case] expected: Rect<f32>,
         ) {
-            assert_eq!(input.resolve_or_zero(context), expected);
+            assert_eq!(ResolveOrZero::resolve_or_zero(input, context), expected);
         }
 
         #[rstest]
@@ -296,7 +297,7 @@ mod tests {
             #[case] context: Size<Option<f32>>,
             #[case] expected: Rect<f32>,
         ) {
-            assert_eq!(input.resolve_or_zero(context), expected);
+            assert_eq!(ResolveOrZero::resolve_or_zero(input, context), expected);
         }
 
         #[rstest]
@@ -309,13 +310,12 @@ mod tests {
             #[case] context: Size<Option<f32>>,
             #[case] expected: Rect<f32>,
         ) {
-            assert_eq!(input.resolve_or_zero(context), expected);
+            assert_eq!(ResolveOrZero::resolve_or_zero(input, context), expected);
         }
     }
 
     mod resolve_or_zero_rect_dimension_to_rect_f32_via_option {
         use crate::geometry::Rect;
-        use crate::resolve::ResolveOrZero;
         use crate::style::Dimension;
         use rstest::rstest;
 
@@ -5,7 +5,7 @@
 /// For Grid it controls alignment in the block axis
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/align-items)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignItems {
     /// Items are packed toward the start of the cross axis
@@ -45,7 +45,7 @@ pub type JustifySelf = AlignItems;
 /// For Grid it controls alignment in the block axis
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/align-content)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AlignContent {
     /// Items are packed toward the start of the axis
@@ -71,5 +71,9 @@ pub enum AlignContent {
 /// For Flexbox it controls alignment in the main axis
 /// For Grid it controls alignment in the inline axis
 ///
+/// In Flexbox, [`Start`](AlignContent::Start) and [`End`](AlignContent::End) are flow-relative: they pack items towards
+/// the main-start and main-end edges, so behave like `flex-start` and `flex-end` and flip for reversed flex directions.
+/// Only the left-to-right `direction` is supported, so there is no separate writing-mode relative alignment.
+///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content)
 pub type JustifyContent = AlignContent;
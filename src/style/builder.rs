@@ -0,0 +1,157 @@
+//! A chainable alternative to constructing [`Style`] with struct update syntax
+use super::{
+    AlignContent, AlignItems, AlignSelf, ContentVisibility, Dimension, Display, FlexDirection, FlexWrap,
+    JustifyContent, LengthPercentage, LengthPercentageAuto, Overflow, Position, Style, TextAlign,
+};
+use crate::geometry::{Point, Rect, Size};
+
+#[cfg(feature = "grid")]
+use super::{GridAutoFlow, GridPlacement, GridTemplate, NonRepeatedTrackSizingFunction, TrackSizingFunction};
+#[cfg(feature = "grid")]
+use crate::geometry::Line;
+#[cfg(feature = "grid")]
+use crate::sys::GridTrackVec;
+
+/// Builds a [`Style`] by setting one field at a time, starting from [`Style::DEFAULT`]
+///
+/// ```
+/// # use taffy::prelude::*;
+/// let style = Style::builder().size(Size::from_points(100.0, 50.0)).flex_grow(1.0).build();
+/// assert_eq!(style, Style { size: Size::from_points(100.0, 50.0), flex_grow: 1.0, ..Default::default() });
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct StyleBuilder {
+    /// The style being built
+    style: Style,
+}
+
+/// Generates a method on [`StyleBuilder`] that sets the [`Style`] field of the same name
+macro_rules! setters {
+    ($($(#[$attr:meta])* $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Sets [`Style::", stringify!($field), "`]")]
+            $(#[$attr])*
+            #[must_use]
+            pub fn $field(mut self, $field: $ty) -> Self {
+                self.style.$field = $field;
+                self
+            }
+        )*
+    };
+}
+
+impl StyleBuilder {
+    /// Creates a builder for the default [`Style`]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { style: Style::DEFAULT }
+    }
+
+    /// Returns the [`Style`] that has been built
+    #[must_use]
+    pub fn build(self) -> Style {
+        self.style
+    }
+
+    setters! {
+        display: Display,
+        content_visibility: ContentVisibility,
+        overflow: Point<Overflow>,
+        scrollbar_width: f32,
+        position: Position,
+        inset: Rect<LengthPercentageAuto>,
+        size: Size<Dimension>,
+        min_size: Size<Dimension>,
+        max_size: Size<Dimension>,
+        aspect_ratio: Option<f32>,
+        margin: Rect<LengthPercentageAuto>,
+        padding: Rect<LengthPercentage>,
+        border: Rect<LengthPercentage>,
+        align_items: Option<AlignItems>,
+        align_self: Option<AlignSelf>,
+        #[cfg(feature = "grid")]
+        justify_items: Option<AlignItems>,
+        justify_self: Option<AlignSelf>,
+        align_content: Option<AlignContent>,
+        justify_content: Option<JustifyContent>,
+        text_align: TextAlign,
+        max_lines: Option<u32>,
+        gap: Size<LengthPercentage>,
+        flex_direction: FlexDirection,
+        flex_wrap: FlexWrap,
+        flex_basis: Dimension,
+        flex_grow: f32,
+        flex_shrink: f32,
+        #[cfg(feature = "grid")]
+        grid_template_rows: GridTrackVec<TrackSizingFunction>,
+        #[cfg(feature = "grid")]
+        grid_template_columns: GridTemplate,
+        #[cfg(feature = "grid")]
+        grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
+        #[cfg(feature = "grid")]
+        grid_auto_columns: GridTrackVec<NonRepeatedTrackSizingFunction>,
+        #[cfg(feature = "grid")]
+        grid_auto_flow: GridAutoFlow,
+        #[cfg(feature = "grid")]
+        grid_row: Line<GridPlacement>,
+        #[cfg(feature = "grid")]
+        grid_column: Line<GridPlacement>,
+        #[cfg(feature = "grid")]
+        order: i32,
+    }
+}
+
+impl Default for StyleBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<StyleBuilder> for Style {
+    fn from(builder: StyleBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl Style {
+    /// Returns a [`StyleBuilder`] for building a [`Style`] one field at a time
+    #[must_use]
+    pub const fn builder() -> StyleBuilder {
+        StyleBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StyleBuilder;
+    use crate::geometry::{Rect, Size};
+    use crate::style::{AlignItems, Dimension, FlexDirection, LengthPercentageAuto, Style};
+
+    #[test]
+    fn builder_matches_struct_literal() {
+        let margin = Rect {
+            left: LengthPercentageAuto::Points(5.0),
+            right: LengthPercentageAuto::Auto,
+            top: LengthPercentageAuto::Percent(0.1),
+            bottom: LengthPercentageAuto::Points(0.0),
+        };
+        let built = Style::builder()
+            .flex_direction(FlexDirection::Column)
+            .align_items(Some(AlignItems::Center))
+            .size(Size { width: Dimension::Points(100.0), height: Dimension::Auto })
+            .margin(margin)
+            .flex_grow(2.0)
+            .build();
+        let literal = Style {
+            flex_direction: FlexDirection::Column,
+            align_items: Some(AlignItems::Center),
+            size: Size { width: Dimension::Points(100.0), height: Dimension::Auto },
+            margin,
+            flex_grow: 2.0,
+            ..Default::default()
+        };
+
+        assert_eq!(built, literal);
+        assert_eq!(StyleBuilder::default().build(), Style::default());
+    }
+}
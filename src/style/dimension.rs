@@ -1,13 +1,18 @@
 //! Style types for representing lengths / sizes
 
+use super::{eq_f32, hash_f32};
 use crate::geometry::{Rect, Size};
+use crate::resolve::{MaybeResolve, ResolveOrZero};
 use crate::style_helpers::{FromPercent, FromPoints, TaffyAuto, TaffyMaxContent, TaffyMinContent, TaffyZero};
 use crate::sys::abs;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
 
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LengthPercentage {
     /// Points are abstract absolute units. Users of Taffy may define what they correspond
@@ -26,14 +31,48 @@ impl FromPoints for LengthPercentage {
 }
 impl FromPercent for LengthPercentage {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Points(percent.into())
+        Self::Percent(percent.into())
+    }
+}
+
+/// Formats the value in CSS syntax (e.g. `10px`, `50%` or `auto`)
+impl fmt::Display for LengthPercentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Points(points) => write!(f, "{points}px"),
+            Self::Percent(fraction) => write!(f, "{}%", fraction * 100.0),
+        }
+    }
+}
+
+impl Hash for LengthPercentage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match *self {
+            Self::Points(value) | Self::Percent(value) => hash_f32(value, state),
+        }
+    }
+}
+
+impl PartialEq for LengthPercentage {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Points(a), Self::Points(b)) | (Self::Percent(a), Self::Percent(b)) => eq_f32(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl From<f32> for LengthPercentage {
+    fn from(points: f32) -> Self {
+        Self::Points(points)
     }
 }
 
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LengthPercentageAuto {
     /// Points are abstract absolute units. Users of Taffy may define what they correspond
@@ -57,7 +96,44 @@ impl FromPoints for LengthPercentageAuto {
 }
 impl FromPercent for LengthPercentageAuto {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Points(percent.into())
+        Self::Percent(percent.into())
+    }
+}
+
+/// Formats the value in CSS syntax (e.g. `10px`, `50%` or `auto`)
+impl fmt::Display for LengthPercentageAuto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Points(points) => write!(f, "{points}px"),
+            Self::Percent(fraction) => write!(f, "{}%", fraction * 100.0),
+            Self::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+impl Hash for LengthPercentageAuto {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match *self {
+            Self::Points(value) | Self::Percent(value) => hash_f32(value, state),
+            Self::Auto => {}
+        }
+    }
+}
+
+impl PartialEq for LengthPercentageAuto {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Points(a), Self::Points(b)) | (Self::Percent(a), Self::Percent(b)) => eq_f32(a, b),
+            (Self::Auto, Self::Auto) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<f32> for LengthPercentageAuto {
+    fn from(points: f32) -> Self {
+        Self::Points(points)
     }
 }
 
@@ -87,7 +163,7 @@ impl LengthPercentageAuto {
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Dimension {
     /// Points are abstract absolute units. Users of Taffy may define what they correspond
@@ -111,7 +187,44 @@ impl FromPoints for Dimension {
 }
 impl FromPercent for Dimension {
     fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Points(percent.into())
+        Self::Percent(percent.into())
+    }
+}
+
+/// Formats the value in CSS syntax (e.g. `10px`, `50%` or `auto`)
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Points(points) => write!(f, "{points}px"),
+            Self::Percent(fraction) => write!(f, "{}%", fraction * 100.0),
+            Self::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+impl Hash for Dimension {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match *self {
+            Self::Points(value) | Self::Percent(value) => hash_f32(value, state),
+            Self::Auto => {}
+        }
+    }
+}
+
+impl PartialEq for Dimension {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Points(a), Self::Points(b)) | (Self::Percent(a), Self::Percent(b)) => eq_f32(a, b),
+            (Self::Auto, Self::Auto) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<f32> for Dimension {
+    fn from(points: f32) -> Self {
+        Self::Points(points)
     }
 }
 
@@ -135,6 +248,18 @@ impl From<LengthPercentageAuto> for Dimension {
 }
 
 impl Dimension {
+    /// Creates a [`Dimension::Points`] with the given value
+    #[must_use]
+    pub const fn points(value: f32) -> Self {
+        Self::Points(value)
+    }
+
+    /// Creates a [`Dimension::Percent`] with the given fraction (where `1.0` is 100%)
+    #[must_use]
+    pub const fn percent(value: f32) -> Self {
+        Self::Percent(value)
+    }
+
     /// Is this value defined?
     pub(crate) fn is_defined(self) -> bool {
         matches!(self, Dimension::Points(_) | Dimension::Percent(_))
@@ -172,6 +297,22 @@ impl Rect<Dimension> {
             bottom: Dimension::Percent(bottom),
         }
     }
+
+    /// Resolve each side into an absolute value, resolving percentages against `context` and `Auto` to zero
+    ///
+    /// Percentage margins and padding resolve against the inline size (width) of the containing block in both axes,
+    /// so callers should pass that width as the `context`. Percentages resolve to zero if `context` is `None`.
+    pub fn resolve_or_zero(self, context: Option<f32>) -> Rect<f32> {
+        ResolveOrZero::resolve_or_zero(self, context)
+    }
+
+    /// Resolve each side into an absolute value, resolving percentages against `context` and `Auto` to `None`
+    ///
+    /// Percentage margins and padding resolve against the inline size (width) of the containing block in both axes,
+    /// so callers should pass that width as the `context`. Percentages resolve to `None` if `context` is `None`.
+    pub fn resolve_or_none(self, context: Option<f32>) -> Rect<Option<f32>> {
+        self.map(|dimension| dimension.maybe_resolve(context))
+    }
 }
 
 /// The amount of space available to a node in a given axis
@@ -215,6 +356,14 @@ impl AvailableSpace {
         }
     }
 
+    /// Return the definite value, or the `fallback` value for `MinContent` and `MaxContent`
+    pub fn definite_or(self, fallback: f32) -> f32 {
+        match self {
+            AvailableSpace::Definite(value) => value,
+            _ => fallback,
+        }
+    }
+
     /// Return the definite value or a default value
     pub fn unwrap_or(self, default: f32) -> f32 {
         self.into_option().unwrap_or(default)
@@ -251,12 +400,31 @@ impl AvailableSpace {
         }
     }
 
+    /// Compute the shrink-to-fit size of a node with the given min-content and max-content sizes
+    ///
+    /// Definite values give `min(max_content, max(min_content, available))`, so the node is no larger than its content
+    /// and no smaller than its min-content size. `MinContent` and `MaxContent` give the matching content size.
+    /// https://www.w3.org/TR/css-sizing-3/#fit-content-size
+    pub fn shrink_to_fit(self, min_content: f32, max_content: f32) -> f32 {
+        match self {
+            AvailableSpace::Definite(available_space) => available_space.min(max_content).max(min_content),
+            AvailableSpace::MinContent => min_content,
+            AvailableSpace::MaxContent => max_content,
+        }
+    }
+
     /// Compare equality with another AvailableSpace, treating definite values
     /// that are within f32::EPSILON of each other as equal
     pub fn is_roughly_equal(self, other: AvailableSpace) -> bool {
+        self.is_roughly_equal_within(other, f32::EPSILON)
+    }
+
+    /// Compare equality with another AvailableSpace, treating definite values
+    /// that are within `epsilon` of each other as equal
+    pub fn is_roughly_equal_within(self, other: AvailableSpace, epsilon: f32) -> bool {
         use AvailableSpace::*;
         match (self, other) {
-            (Definite(a), Definite(b)) => abs(a - b) < f32::EPSILON,
+            (Definite(a), Definite(b)) => abs(a - b) < epsilon,
             (MinContent, MinContent) => true,
             (MaxContent, MaxContent) => true,
             _ => false,
@@ -290,3 +458,102 @@ impl Size<AvailableSpace> {
         Size { width: self.width.maybe_set(value.width), height: self.height.maybe_set(value.height) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+    use crate::geometry::{Rect, Size};
+    use crate::style_helpers::{auto, percent, points, TaffyUnits};
+
+    #[test]
+    fn f32_into_dimension_is_points() {
+        let dimension: Dimension = 10.0.into();
+        assert_eq!(dimension, Dimension::Points(10.0));
+        let length: LengthPercentage = 10.0.into();
+        assert_eq!(length, LengthPercentage::Points(10.0));
+        let length: LengthPercentageAuto = 10.0.into();
+        assert_eq!(length, LengthPercentageAuto::Points(10.0));
+    }
+
+    #[test]
+    fn f32_unit_methods_create_matching_variants() {
+        assert_eq!(10.0_f32.points::<Dimension>(), Dimension::Points(10.0));
+        assert_eq!(0.5_f32.percent::<Dimension>(), Dimension::Percent(0.5));
+        let length: LengthPercentage = 0.5_f32.percent();
+        assert_eq!(length, LengthPercentage::Percent(0.5));
+        let size: Size<LengthPercentageAuto> = 10.0_f32.points();
+        assert_eq!(
+            size,
+            Size { width: LengthPercentageAuto::Points(10.0), height: LengthPercentageAuto::Points(10.0) }
+        );
+    }
+
+    #[test]
+    fn dimension_constructors() {
+        assert_eq!(Dimension::points(10.0), Dimension::Points(10.0));
+        assert_eq!(Dimension::percent(0.5), Dimension::Percent(0.5));
+    }
+
+    #[test]
+    fn style_helpers_create_matching_variants() {
+        assert_eq!(points::<f32, Dimension>(10.0), Dimension::Points(10.0));
+        assert_eq!(percent::<f32, Dimension>(0.5), Dimension::Percent(0.5));
+        assert_eq!(percent::<f32, LengthPercentage>(0.5), LengthPercentage::Percent(0.5));
+        assert_eq!(percent::<f32, LengthPercentageAuto>(0.5), LengthPercentageAuto::Percent(0.5));
+    }
+
+    #[test]
+    fn shrink_to_fit_clamps_between_content_sizes() {
+        assert_eq!(AvailableSpace::Definite(100.0).shrink_to_fit(30.0, 60.0), 60.0);
+        assert_eq!(AvailableSpace::Definite(45.0).shrink_to_fit(30.0, 60.0), 45.0);
+        assert_eq!(AvailableSpace::Definite(10.0).shrink_to_fit(30.0, 60.0), 30.0);
+        assert_eq!(AvailableSpace::MinContent.shrink_to_fit(30.0, 60.0), 30.0);
+        assert_eq!(AvailableSpace::MaxContent.shrink_to_fit(30.0, 60.0), 60.0);
+    }
+
+    #[test]
+    fn display_uses_css_units() {
+        assert_eq!(format!("{}", Dimension::Points(10.0)), "10px");
+        assert_eq!(format!("{}", Dimension::Percent(0.5)), "50%");
+        assert_eq!(format!("{}", Dimension::Auto), "auto");
+        assert_eq!(format!("{}", LengthPercentage::Points(2.5)), "2.5px");
+        assert_eq!(format!("{}", LengthPercentageAuto::Auto), "auto");
+    }
+
+    #[test]
+    fn display_size_and_rect_compactly() {
+        let size: Size<Dimension> = Size { width: points(10.0), height: auto() };
+        assert_eq!(format!("{size}"), "Size(10px × auto)");
+        let rect: Rect<LengthPercentage> =
+            Rect { left: points(1.0), right: points(2.0), top: percent(0.25), bottom: points(0.0) };
+        assert_eq!(format!("{rect}"), "Rect(1px, 2px, 25%, 0px)");
+    }
+
+    #[test]
+    fn available_space_definite_or() {
+        assert_eq!(AvailableSpace::Definite(10.0).definite_or(5.0), 10.0);
+        assert_eq!(AvailableSpace::MinContent.definite_or(5.0), 5.0);
+        assert_eq!(AvailableSpace::MaxContent.definite_or(5.0), 5.0);
+    }
+
+    #[test]
+    fn available_space_into_option() {
+        assert_eq!(AvailableSpace::Definite(10.0).into_option(), Some(10.0));
+        assert_eq!(AvailableSpace::MinContent.into_option(), None);
+        assert_eq!(AvailableSpace::MaxContent.into_option(), None);
+    }
+
+    #[test]
+    fn resolve_rect_of_mixed_dimensions() {
+        let rect: Rect<Dimension> =
+            Rect { left: points(10.0), right: percent(0.5), top: auto(), bottom: percent(0.25) };
+
+        assert_eq!(rect.resolve_or_zero(Some(200.0)), Rect { left: 10.0, right: 100.0, top: 0.0, bottom: 50.0 });
+        assert_eq!(rect.resolve_or_zero(None), Rect { left: 10.0, right: 0.0, top: 0.0, bottom: 0.0 });
+        assert_eq!(
+            rect.resolve_or_none(Some(200.0)),
+            Rect { left: Some(10.0), right: Some(100.0), top: None, bottom: Some(50.0) }
+        );
+        assert_eq!(rect.resolve_or_none(None), Rect { left: Some(10.0), right: None, top: None, bottom: None });
+    }
+}
@@ -1,5 +1,5 @@
 //! Style types for CSS Grid layout
-use super::{AlignContent, LengthPercentage, Style};
+use super::{eq_f32, hash_f32, AlignContent, LengthPercentage, Style};
 use crate::axis::{AbsoluteAxis, AbstractAxis};
 use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine};
 use crate::geometry::{Line, MinMax};
@@ -7,6 +7,8 @@ use crate::style::AvailableSpace;
 use crate::style_helpers::*;
 use crate::sys::GridTrackVec;
 use core::cmp::{max, min};
+use core::hash::{Hash, Hasher};
+use core::mem;
 
 /// Controls whether grid items are placed row-wise or column-wise. And whether the sparse or dense packing algorithm is used.
 ///
@@ -15,7 +17,7 @@ use core::cmp::{max, min};
 /// Defaults to [`GridAutoFlow::Row`]
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow)
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GridAutoFlow {
     /// Items are placed by filling each row in turn, adding new rows as necessary
@@ -61,7 +63,7 @@ impl GridAutoFlow {
 /// GenericGridPlacement<OriginZeroLine> is aliased as OriginZeroGridPlacement and is used internally for placement computations.
 ///
 /// See [`crate::compute::grid::type::coordinates`] for documentation on the different coordinate systems.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GenericGridPlacement<LineType: GridCoordinate> {
     /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
@@ -237,7 +239,8 @@ impl Default for Line<GridPlacement> {
 /// Specifies the maximum size of a grid track. A grid track will automatically size between it's minimum and maximum size based
 /// on the size of it's contents, the amount of available space, and the sizing constraint the grid is being size under.
 /// See https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MaxTrackSizingFunction {
     /// Track maximum size should be a fixed points or percentage value
     Fixed(LengthPercentage),
@@ -287,6 +290,27 @@ impl FromFlex for MaxTrackSizingFunction {
     }
 }
 
+impl Hash for MaxTrackSizingFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match *self {
+            Self::Fixed(length) | Self::FitContent(length) => length.hash(state),
+            Self::Flex(fraction) => hash_f32(fraction, state),
+            Self::MinContent | Self::MaxContent | Self::Auto => {}
+        }
+    }
+}
+
+impl PartialEq for MaxTrackSizingFunction {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::Fixed(a), Self::Fixed(b)) | (Self::FitContent(a), Self::FitContent(b)) => a == b,
+            (Self::Flex(a), Self::Flex(b)) => eq_f32(a, b),
+            (a, b) => mem::discriminant(&a) == mem::discriminant(&b),
+        }
+    }
+}
+
 impl MaxTrackSizingFunction {
     /// Returns true if the max track sizing function is `MinContent`, `MaxContent` or `Auto`, else false.
     #[inline(always)]
@@ -330,7 +354,8 @@ impl MaxTrackSizingFunction {
 /// Specifies the minimum size of a grid track. A grid track will automatically size between it's minimum and maximum size based
 /// on the size of it's contents, the amount of available space, and the sizing constraint the grid is being size under.
 /// See https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MinTrackSizingFunction {
     /// Track minimum size should be a fixed points or percentage value
     Fixed(LengthPercentage),
@@ -439,7 +464,8 @@ impl FromFlex for NonRepeatedTrackSizingFunction {
 ///
 /// See https://www.w3.org/TR/css-grid-1/#auto-repeat for an explanation of how auto-repeated track definitions work
 /// and the difference between AutoFit and AutoFill.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GridTrackRepetition {
     /// Auto-repeating track should be generated to fit the container
     /// See: https://developer.mozilla.org/en-US/docs/Web/CSS/repeat#auto-fill
@@ -451,7 +477,8 @@ pub enum GridTrackRepetition {
 
 /// The sizing function for a grid track (row/column)
 /// See https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TrackSizingFunction {
     /// A single non-repeated track
     Single(NonRepeatedTrackSizingFunction),
@@ -465,6 +492,46 @@ impl TrackSizingFunction {
         matches!(self, Self::AutoRepeat(_, _))
     }
 }
+
+/// The explicit tracks of a grid container in the column axis
+///
+/// See https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns
+#[derive(Clone, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridTemplate {
+    /// The container defines its own tracks
+    Tracks(GridTrackVec<TrackSizingFunction>),
+    /// The container is a subgrid: it adopts the size of each of the parent grid's tracks that it spans, along with the
+    /// gutters between them. If the container is not an in-flow child of a grid then it has no explicit tracks.
+    ///
+    /// The items of a subgrid do not contribute to the sizing of the parent's tracks, and the subgrid's own padding and
+    /// border are not taken into account.
+    Subgrid,
+}
+impl GridTemplate {
+    /// The tracks defined by the template, which is empty for a subgrid
+    pub fn tracks(&self) -> &[TrackSizingFunction] {
+        match self {
+            Self::Tracks(tracks) => tracks,
+            Self::Subgrid => &[],
+        }
+    }
+
+    /// Whether the template is [`GridTemplate::Subgrid`]
+    pub fn is_subgrid(&self) -> bool {
+        matches!(self, Self::Subgrid)
+    }
+}
+impl Default for GridTemplate {
+    fn default() -> Self {
+        Self::Tracks(GridTrackVec::new())
+    }
+}
+impl From<GridTrackVec<TrackSizingFunction>> for GridTemplate {
+    fn from(tracks: GridTrackVec<TrackSizingFunction>) -> Self {
+        Self::Tracks(tracks)
+    }
+}
 impl TaffyAuto for TrackSizingFunction {
     const AUTO: Self = Self::Single(NonRepeatedTrackSizingFunction::AUTO);
 }
@@ -506,9 +573,9 @@ impl From<MinMax<MinTrackSizingFunction, MaxTrackSizingFunction>> for TrackSizin
 // Grid extensions to the Style struct
 impl Style {
     /// Get a grid item's row or column placement depending on the axis passed
-    pub(crate) fn grid_template_tracks(&self, axis: AbsoluteAxis) -> &GridTrackVec<TrackSizingFunction> {
+    pub(crate) fn grid_template_tracks(&self, axis: AbsoluteAxis) -> &[TrackSizingFunction] {
         match axis {
-            AbsoluteAxis::Horizontal => &self.grid_template_columns,
+            AbsoluteAxis::Horizontal => self.grid_template_columns.tracks(),
             AbsoluteAxis::Vertical => &self.grid_template_rows,
         }
     }
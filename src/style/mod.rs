@@ -1,9 +1,11 @@
 //! A representation of [CSS layout properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust, used for flexbox layout
 mod alignment;
+mod builder;
 mod dimension;
 mod flex;
 
 pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
+pub use self::builder::StyleBuilder;
 pub use self::dimension::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
 pub use self::flex::{FlexDirection, FlexWrap};
 
@@ -13,10 +15,11 @@ mod grid;
 pub(crate) use self::grid::{GenericGridPlacement, OriginZeroGridPlacement};
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplate, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
     NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
-use crate::geometry::{Rect, Size};
+use crate::geometry::{Point, Rect, Size};
+use core::hash::{Hash, Hasher};
 
 #[cfg(feature = "grid")]
 use crate::geometry::Line;
@@ -26,7 +29,7 @@ use crate::sys::GridTrackVec;
 /// Sets the layout used for the children of this node
 ///
 /// [`Display::Flex`] is the default value.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Display {
     /// The children will follow the flexbox layout algorithm
@@ -36,6 +39,11 @@ pub enum Display {
     Grid,
     /// The children will not be laid out, and will follow absolute positioning
     None,
+    /// The node does not generate a box of its own: its children are laid out as if they were children of its parent
+    ///
+    /// This is currently only supported for children of flexbox containers. Elsewhere (e.g. as the root node or as a
+    /// grid item) the node is laid out as if it had [`Display::Flex`].
+    Contents,
 }
 
 impl Default for Display {
@@ -53,7 +61,7 @@ impl Default for Display {
 /// which can be unintuitive.
 ///
 /// [`PositionType::Relative`] is the default value, in contrast to the default behavior in CSS.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Position {
     /// The offset is computed relative to the final position given by the layout algorithm.
@@ -65,6 +73,11 @@ pub enum Position {
     ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
     Absolute,
+    /// The offset is computed relative to the root node, regardless of any positioned ancestors.
+    /// As with [`Position::Absolute`], no space is created for the item in the page layout.
+    ///
+    /// Percentage sizes, margins and offsets are resolved against the size of the root node.
+    Fixed,
 }
 
 impl Default for Position {
@@ -73,6 +86,73 @@ impl Default for Position {
     }
 }
 
+impl Position {
+    /// Whether the item is taken out of the normal flow of its parent ([`Position::Absolute`] or [`Position::Fixed`])
+    pub(crate) fn is_absolutely_positioned(self) -> bool {
+        matches!(self, Self::Absolute | Self::Fixed)
+    }
+}
+
+/// How children overflowing their container should affect layout
+///
+/// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
+/// be displayed anyway, be clipped, or trigger the container to become a scroll container. Taffy only uses it to determine whether
+/// space should be reserved for a scrollbar (see [`Style::scrollbar_width`]) and which content contributes to [`Layout::scroll_size`](crate::layout::Layout::scroll_size).
+///
+/// It is set separately for each axis (see [`Style::overflow`]): the `x` value controls the horizontal scrollbar and the `y`
+/// value controls the vertical scrollbar.
+///
+/// [`Overflow::Visible`] is the default value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Overflow {
+    /// Overflowing content is visible. No space is reserved for scrollbars.
+    #[default]
+    Visible,
+    /// Overflowing content is clipped. No space is reserved for scrollbars.
+    Hidden,
+    /// The node is a scroll container. Space is always reserved for a scrollbar in this axis.
+    Scroll,
+    /// The node is a scroll container. Space is reserved for a scrollbar in this axis only if the content overflows in it.
+    Auto,
+}
+
+/// Controls whether the contents of a node are laid out
+///
+/// This is useful for virtualization, where subtrees that are offscreen can be skipped.
+///
+/// [`ContentVisibility::Visible`] is the default value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContentVisibility {
+    /// The contents of the node are laid out as normal
+    #[default]
+    Visible,
+    /// The contents of the node are skipped. The node is sized from its known dimensions or `size` style, falling back
+    /// to its padding and border as if it were empty, and the layouts of its children are left untouched.
+    Hidden,
+}
+
+/// How the lines of text within a node should be aligned in the inline axis
+///
+/// Taffy does not lay out text itself, so this value has no effect on layout. It is stored alongside the rest of the
+/// style so that measure functions and renderers can read it from the same place.
+///
+/// [`TextAlign::Start`] is the default value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextAlign {
+    /// Lines are aligned to the start of the line box
+    #[default]
+    Start,
+    /// Lines are centered within the line box
+    Center,
+    /// Lines are aligned to the end of the line box
+    End,
+    /// Lines are stretched to fill the line box
+    Justify,
+}
+
 /// The flexbox layout information for a single [`Node`](crate::node::Node).
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -87,17 +167,31 @@ impl Default for Position {
 /// this [introduction to the box model](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Box_Model/Introduction_to_the_CSS_box_model).
 ///
 /// If the behavior does not match the flexbox layout algorithm on the web, please file a bug!
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Style {
     /// What layout strategy should be used?
     pub display: Display,
+    /// Should the contents of this node be laid out?
+    ///
+    /// The size of a node whose contents are skipped is not recomputed from its contents: it is sized as if it were
+    /// empty (see [`ContentVisibility::Hidden`]).
+    pub content_visibility: ContentVisibility,
+
+    /// How children overflowing their container should affect layout, in each axis
+    pub overflow: Point<Overflow>,
+    /// How much space (in points) should be reserved for the scrollbars of a scroll container
+    ///
+    /// Currently only respected by the flexbox algorithm.
+    pub scrollbar_width: f32,
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
     pub position: Position,
     /// How should the position of this element be tweaked relative to the layout defined?
+    ///
+    /// For absolutely positioned nodes, an axis in which both insets are `auto` keeps the node's static position.
     pub inset: Rect<LengthPercentageAuto>,
 
     // Size properies
@@ -133,10 +227,30 @@ pub struct Style {
     /// Falls back to the parents [`JustifyItems`] if not set
     pub justify_self: Option<AlignSelf>,
     /// How should content contained within this item be aligned in the cross/block axis
+    ///
+    /// `None` represents the CSS `normal` value. This behaves as [`AlignContent::Stretch`], which stretches the lines of a
+    /// multi-line flex container and the `auto` rows of a grid. Grids without `auto` rows are aligned to the start.
     pub align_content: Option<AlignContent>,
     /// How should contained within this item be aligned in the main/inline axis
     pub justify_content: Option<JustifyContent>,
+    /// How should lines of text within this item be aligned in the inline axis?
+    ///
+    /// This is not used by Taffy's layout algorithms, see [`TextAlign`].
+    pub text_align: TextAlign,
+    /// The maximum number of lines of text that this node should show, like `-webkit-line-clamp`
+    ///
+    /// Taffy does not lay out text itself, so this is passed to the node's measure function (see
+    /// [`MeasureFunc::RawWithMaxLines`](crate::node::MeasureFunc::RawWithMaxLines)), which should limit the height that
+    /// it returns accordingly. `None` (the default) places no limit on the number of lines.
+    pub max_lines: Option<u32>,
     /// How large should the gaps between items in a grid or flex container be?
+    ///
+    /// The `width` is the `column-gap` (the gap in the inline axis) and the `height` is the `row-gap` (the gap in
+    /// the block axis). Only the horizontal writing mode is supported, so these always map onto the physical axes.
+    ///
+    /// Percentages resolve against the size of the container's content box. They resolve to zero in an axis in which
+    /// that size is indefinite, except for the main axis gap of a flex container, which is resolved against the length
+    /// of its longest flex line.
     pub gap: Size<LengthPercentage>,
 
     // Flexbox properies
@@ -159,9 +273,9 @@ pub struct Style {
     /// Defines the track sizing functions (widths) of the grid rows
     #[cfg(feature = "grid")]
     pub grid_template_rows: GridTrackVec<TrackSizingFunction>,
-    /// Defines the track sizing functions (heights) of the grid columns
+    /// Defines the track sizing functions (heights) of the grid columns, or makes the node a subgrid in the column axis
     #[cfg(feature = "grid")]
-    pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+    pub grid_template_columns: GridTemplate,
     /// Defines the size of implicitly created rows
     #[cfg(feature = "grid")]
     pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
@@ -179,12 +293,21 @@ pub struct Style {
     /// Defines which column in the grid the item should start and end at
     #[cfg(feature = "grid")]
     pub grid_column: Line<GridPlacement>,
+    /// Controls the order in which the item is auto-placed and painted relative to its siblings
+    ///
+    /// Items with a lower `order` come first, and items with the same `order` keep their document order.
+    /// Currently only respected by the CSS Grid algorithm.
+    #[cfg(feature = "grid")]
+    pub order: i32,
 }
 
 impl Style {
     /// The [`Default`] layout, in a form that can be used in const functions
     pub const DEFAULT: Style = Style {
         display: Display::Flex,
+        content_visibility: ContentVisibility::Visible,
+        overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
+        scrollbar_width: 0.0,
         position: Position::Relative,
         flex_direction: FlexDirection::Row,
         flex_wrap: FlexWrap::NoWrap,
@@ -195,6 +318,8 @@ impl Style {
         justify_self: None,
         align_content: None,
         justify_content: None,
+        text_align: TextAlign::Start,
+        max_lines: None,
         inset: Rect::auto(),
         margin: Rect::zero(),
         padding: Rect::zero(),
@@ -210,7 +335,7 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_template_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
-        grid_template_columns: GridTrackVec::new(),
+        grid_template_columns: GridTemplate::Tracks(GridTrackVec::new()),
         #[cfg(feature = "grid")]
         grid_auto_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
@@ -221,7 +346,39 @@ impl Style {
         grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
+        #[cfg(feature = "grid")]
+        order: 0,
     };
+
+    /// The default layout with the `flex` shorthand set to a single number, as in CSS `flex: <grow>`
+    ///
+    /// This sets `flex_grow` to `grow`, `flex_shrink` to `1.0` and `flex_basis` to `0`. Other fields can be set using
+    /// struct update syntax: `Style { size: Size::from_points(10.0, 10.0), ..Style::flex(1.0) }`
+    pub const fn flex(grow: f32) -> Style {
+        let mut style = Style::DEFAULT;
+        style.flex_grow = grow;
+        style.flex_shrink = 1.0;
+        style.flex_basis = Dimension::Points(0.0);
+        style
+    }
+
+    /// The default layout with the `flex` shorthand set to `auto`, equivalent to `flex: 1 1 auto`
+    pub const fn flex_auto() -> Style {
+        let mut style = Style::DEFAULT;
+        style.flex_grow = 1.0;
+        style.flex_shrink = 1.0;
+        style.flex_basis = Dimension::Auto;
+        style
+    }
+
+    /// The default layout with the `flex` shorthand set to `none`, equivalent to `flex: 0 0 auto`
+    pub const fn flex_none() -> Style {
+        let mut style = Style::DEFAULT;
+        style.flex_grow = 0.0;
+        style.flex_shrink = 0.0;
+        style.flex_basis = Dimension::Auto;
+        style
+    }
 }
 
 impl Default for Style {
@@ -230,6 +387,191 @@ impl Default for Style {
     }
 }
 
+/// Styles compare their `f32` values numerically, so `0.0` and `-0.0` are equal, except that all NaN values are equal to
+/// each other (so that every style is equal to itself).
+impl PartialEq for Style {
+    fn eq(&self, other: &Self) -> bool {
+        // Destructured so that a new field cannot be added without also being compared (as it is in `Hash`)
+        let Style {
+            display,
+            content_visibility,
+            overflow,
+            scrollbar_width,
+            position,
+            inset,
+            size,
+            min_size,
+            max_size,
+            aspect_ratio,
+            margin,
+            padding,
+            border,
+            align_items,
+            align_self,
+            #[cfg(feature = "grid")]
+            justify_items,
+            justify_self,
+            align_content,
+            justify_content,
+            text_align,
+            max_lines,
+            gap,
+            flex_direction,
+            flex_wrap,
+            flex_basis,
+            flex_grow,
+            flex_shrink,
+            #[cfg(feature = "grid")]
+            grid_template_rows,
+            #[cfg(feature = "grid")]
+            grid_template_columns,
+            #[cfg(feature = "grid")]
+            grid_auto_rows,
+            #[cfg(feature = "grid")]
+            grid_auto_columns,
+            #[cfg(feature = "grid")]
+            grid_auto_flow,
+            #[cfg(feature = "grid")]
+            grid_row,
+            #[cfg(feature = "grid")]
+            grid_column,
+            #[cfg(feature = "grid")]
+            order,
+        } = self;
+
+        let equal = *display == other.display
+            && *content_visibility == other.content_visibility
+            && *overflow == other.overflow
+            && eq_f32(*scrollbar_width, other.scrollbar_width)
+            && *position == other.position
+            && *inset == other.inset
+            && *size == other.size
+            && *min_size == other.min_size
+            && *max_size == other.max_size
+            && match (*aspect_ratio, other.aspect_ratio) {
+                (Some(a), Some(b)) => eq_f32(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+            && *margin == other.margin
+            && *padding == other.padding
+            && *border == other.border
+            && *align_items == other.align_items
+            && *align_self == other.align_self
+            && *justify_self == other.justify_self
+            && *align_content == other.align_content
+            && *justify_content == other.justify_content
+            && *text_align == other.text_align
+            && *max_lines == other.max_lines
+            && *gap == other.gap
+            && *flex_direction == other.flex_direction
+            && *flex_wrap == other.flex_wrap
+            && *flex_basis == other.flex_basis
+            && eq_f32(*flex_grow, other.flex_grow)
+            && eq_f32(*flex_shrink, other.flex_shrink);
+        #[cfg(feature = "grid")]
+        let equal = equal
+            && *justify_items == other.justify_items
+            && *grid_template_rows == other.grid_template_rows
+            && *grid_template_columns == other.grid_template_columns
+            && *grid_auto_rows == other.grid_auto_rows
+            && *grid_auto_columns == other.grid_auto_columns
+            && *grid_auto_flow == other.grid_auto_flow
+            && *grid_row == other.grid_row
+            && *grid_column == other.grid_column
+            && *order == other.order;
+        equal
+    }
+}
+
+impl Eq for Style {}
+
+/// Styles that are equal hash equally. All NaN values are hashed identically.
+impl Hash for Style {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Style {
+            display,
+            content_visibility,
+            overflow,
+            scrollbar_width,
+            position,
+            inset,
+            size,
+            min_size,
+            max_size,
+            aspect_ratio,
+            margin,
+            padding,
+            border,
+            align_items,
+            align_self,
+            #[cfg(feature = "grid")]
+            justify_items,
+            justify_self,
+            align_content,
+            justify_content,
+            text_align,
+            max_lines,
+            gap,
+            flex_direction,
+            flex_wrap,
+            flex_basis,
+            flex_grow,
+            flex_shrink,
+            #[cfg(feature = "grid")]
+            grid_template_rows,
+            #[cfg(feature = "grid")]
+            grid_template_columns,
+            #[cfg(feature = "grid")]
+            grid_auto_rows,
+            #[cfg(feature = "grid")]
+            grid_auto_columns,
+            #[cfg(feature = "grid")]
+            grid_auto_flow,
+            #[cfg(feature = "grid")]
+            grid_row,
+            #[cfg(feature = "grid")]
+            grid_column,
+            #[cfg(feature = "grid")]
+            order,
+        } = self;
+
+        (display, content_visibility, overflow, position, inset, size, min_size, max_size).hash(state);
+        hash_f32(*scrollbar_width, state);
+        aspect_ratio.is_some().hash(state);
+        hash_f32(aspect_ratio.unwrap_or(0.0), state);
+        (margin, padding, border, align_items, align_self, justify_self, align_content, justify_content).hash(state);
+        (text_align, max_lines, gap, flex_direction, flex_wrap, flex_basis).hash(state);
+        hash_f32(*flex_grow, state);
+        hash_f32(*flex_shrink, state);
+        #[cfg(feature = "grid")]
+        {
+            (justify_items, grid_template_rows, grid_template_columns).hash(state);
+            (grid_auto_rows, grid_auto_columns, grid_auto_flow, grid_row, grid_column, order).hash(state);
+        }
+    }
+}
+
+/// Compares two `f32`s numerically, except that all NaN values are equal to each other
+///
+/// This is how the `PartialEq` implementations of the style types compare their `f32` values, so that they are reflexive.
+pub(crate) fn eq_f32(a: f32, b: f32) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+/// Feeds an `f32` into `state` consistently with [`eq_f32`]
+///
+/// `0.0` and `-0.0` compare equal so are hashed identically, and all NaN values are hashed identically too.
+pub(crate) fn hash_f32<H: Hasher>(value: f32, state: &mut H) {
+    let bits = if value == 0.0 {
+        0
+    } else if value.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        value.to_bits()
+    };
+    bits.hash(state);
+}
+
 #[cfg(test)]
 mod tests {
     use super::Style;
@@ -242,6 +584,9 @@ mod tests {
 
         let old_defaults = Style {
             display: Default::default(),
+            content_visibility: Default::default(),
+            overflow: Default::default(),
+            scrollbar_width: 0.0,
             position: Default::default(),
             flex_direction: Default::default(),
             flex_wrap: Default::default(),
@@ -252,6 +597,8 @@ mod tests {
             justify_self: Default::default(),
             align_content: Default::default(),
             justify_content: Default::default(),
+            text_align: Default::default(),
+            max_lines: None,
             inset: Rect::auto(),
             margin: Rect::zero(),
             padding: Rect::zero(),
@@ -278,12 +625,76 @@ mod tests {
             grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
             #[cfg(feature = "grid")]
             grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
+            #[cfg(feature = "grid")]
+            order: 0,
         };
 
         assert_eq!(Style::DEFAULT, Style::default());
         assert_eq!(Style::DEFAULT, old_defaults);
     }
 
+    #[test]
+    fn equal_styles_hash_equally() {
+        use super::Dimension;
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(style: &Style) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            style.hash(&mut hasher);
+            hasher.finish()
+        }
+        let make_style = |width: f32| Style {
+            size: Size { width: Dimension::Points(width), height: Dimension::Percent(0.5) },
+            flex_grow: 1.0,
+            ..Default::default()
+        };
+
+        assert_eq!(make_style(10.0), make_style(10.0));
+        assert_eq!(hash(&make_style(10.0)), hash(&make_style(10.0)));
+        assert_ne!(make_style(10.0), make_style(20.0));
+        assert_ne!(hash(&make_style(10.0)), hash(&make_style(20.0)));
+
+        // Zeros of either sign are equal, and NaNs are equal to each other (so equality is reflexive)
+        assert_eq!(make_style(0.0), make_style(-0.0));
+        assert_eq!(hash(&make_style(0.0)), hash(&make_style(-0.0)));
+        assert_eq!(make_style(f32::NAN), make_style(-f32::NAN));
+        assert_eq!(hash(&make_style(f32::NAN)), hash(&make_style(-f32::NAN)));
+        assert_ne!(make_style(f32::NAN), make_style(10.0));
+
+        let nan_style = Style { flex_grow: f32::NAN, aspect_ratio: Some(f32::NAN), ..Default::default() };
+        assert_eq!(nan_style, nan_style.clone());
+        assert_ne!(nan_style, Style::default());
+    }
+
+    #[test]
+    fn flex_shorthand_matches_longhands() {
+        use super::Dimension;
+        use crate::style_helpers::TaffyMaxContent;
+        use crate::Taffy;
+
+        let longhands =
+            Style { flex_grow: 1.0, flex_shrink: 1.0, flex_basis: Dimension::Points(0.0), ..Style::DEFAULT };
+        assert_eq!(Style::flex(1.0), longhands);
+
+        let mut taffy = Taffy::new();
+        let shorthand_child =
+            taffy.new_leaf(Style { size: Size::from_points(50.0, 10.0), ..Style::flex(1.0) }).unwrap();
+        let longhand_child = taffy.new_leaf(Style { size: Size::from_points(50.0, 10.0), ..longhands }).unwrap();
+        let none_child = taffy.new_leaf(Style { size: Size::from_points(50.0, 10.0), ..Style::flex_none() }).unwrap();
+        let node = taffy
+            .new_with_children(
+                Style { size: Size::from_points(300.0, 10.0), ..Default::default() },
+                &[shorthand_child, longhand_child, none_child],
+            )
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(shorthand_child).unwrap().size, taffy.layout(longhand_child).unwrap().size);
+        assert_eq!(taffy.layout(shorthand_child).unwrap().size.width, 125.0);
+        assert_eq!(taffy.layout(none_child).unwrap().size.width, 50.0);
+    }
+
     // NOTE: Please feel free the update the sizes in this test as required. This test is here to prevent unintentional size changes
     // and to serve as accurate up-to-date documentation on the sizes.
     #[test]
@@ -310,6 +721,8 @@ mod tests {
 
         // Display and Position
         assert_type_size::<Display>(1);
+        assert_type_size::<ContentVisibility>(1);
+        assert_type_size::<Overflow>(1);
         assert_type_size::<Position>(1);
 
         // Dimensions and aggregations of Dimensions
@@ -347,6 +760,6 @@ mod tests {
         assert_type_size::<Line<GridPlacement>>(8);
 
         // Overall
-        assert_type_size::<Style>(344);
+        assert_type_size::<Style>(360);
     }
 }
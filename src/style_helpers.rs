@@ -6,12 +6,14 @@ use crate::{
 
 #[cfg(feature = "grid")]
 use crate::style::{GridTrackRepetition, NonRepeatedTrackSizingFunction, TrackSizingFunction};
+#[cfg(feature = "grid")]
+use crate::sys::GridTrackVec;
 
 /// Returns an auto-repeated track definition
 #[cfg(feature = "grid")]
 pub fn repeat(
     repetition_kind: GridTrackRepetition,
-    track_list: Vec<NonRepeatedTrackSizingFunction>,
+    track_list: GridTrackVec<NonRepeatedTrackSizingFunction>,
 ) -> TrackSizingFunction {
     TrackSizingFunction::AutoRepeat(repetition_kind, track_list)
 }
@@ -401,6 +403,23 @@ impl<T: FromPercent> Rect<T> {
     }
 }
 
+/// Extension methods to create points and percent values from `f32`s (e.g. `10.0_f32.points()` or `0.5_f32.percent()`)
+///
+/// Like [`points`] and [`percent`], these return a value of the inferred type. Float literals must be suffixed with
+/// `f32` (or otherwise have their type known) for the methods to be found.
+pub trait TaffyUnits: Into<f32> + Copy {
+    /// Returns a value of the inferred type which represents a constant of points
+    fn points<T: FromPoints>(self) -> T {
+        T::from_points(self)
+    }
+
+    /// Returns a value of the inferred type which represents a constant percentage (where `1.0` is 100%)
+    fn percent<T: FromPercent>(self) -> T {
+        T::from_percent(self)
+    }
+}
+impl TaffyUnits for f32 {}
+
 /// Returns a value of the inferred type which represents a flex fraction
 pub fn flex<Input: Into<f32> + Copy, T: FromFlex>(flex: Input) -> T {
     T::from_flex(flex)
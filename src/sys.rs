@@ -68,6 +68,7 @@ mod alloc {
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = alloc::vec::Vec<A>;
     /// A vector of grid tracks
+    #[cfg(feature = "grid")]
     pub(crate) type GridTrackVec<A> = alloc::vec::Vec<A>;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
@@ -1,20 +1,24 @@
 //! The baseline requirements of any UI Tree so Taffy can efficiently calculate the layout
 
-use slotmap::DefaultKey;
-
 use crate::{
+    data::CACHE_SIZE,
     error::TaffyResult,
-    layout::{Cache, Layout},
+    layout::{Cache, Layout, LayoutWarning},
     prelude::*,
 };
 
+#[cfg(feature = "grid")]
+use crate::layout::SubgridColumns;
+#[cfg(feature = "grid")]
+use crate::sys::Vec;
+
 /// Any item that implements the LayoutTree can be layed out using Taffy's algorithms.
 ///
 /// Generally, Taffy expects your Node tree to be indexable by stable indices. A "stable" index means that the Node's ID
 /// remains the same between re-layouts.
 pub trait LayoutTree {
     /// Type representing an iterator of the children of a node
-    type ChildIter<'a>: Iterator<Item = &'a DefaultKey>
+    type ChildIter<'a>: Iterator<Item = &'a Node>
     where
         Self: 'a;
 
@@ -58,9 +62,78 @@ pub trait LayoutTree {
         available_space: Size<AvailableSpace>,
     ) -> Size<f32>;
 
+    /// Measure a node, honouring `max_lines`: the [`max_lines`](crate::style::Style::max_lines) style of the node
+    ///
+    /// This is what the layout algorithms call. The default implementation ignores `max_lines` and delegates to
+    /// [`LayoutTree::measure_node`].
+    fn measure_node_with_max_lines(
+        &self,
+        node: Node,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _max_lines: Option<u32>,
+    ) -> Size<f32> {
+        self.measure_node(node, known_dimensions, available_space)
+    }
+
     /// Node needs to be measured
     fn needs_measure(&self, node: Node) -> bool;
 
+    /// The number of cache entries stored for each node
+    ///
+    /// Valid indexes for [`LayoutTree::cache_mut`] are `0..self.cache_size()`
+    fn cache_size(&self) -> usize {
+        CACHE_SIZE
+    }
+
+    /// The largest difference between two definite available spaces for which a cached result is reused
+    fn cache_epsilon(&self) -> f32 {
+        f32::EPSILON
+    }
+
     /// Get a cache entry for this Node by index
     fn cache_mut(&mut self, node: Node, index: usize) -> &mut Option<Cache>;
+
+    /// Returns another node whose cached sizes can be reused for `node`, because it has identical styles, measure
+    /// function and descendants
+    ///
+    /// The default implementation never shares cached sizes between nodes.
+    fn shared_cache_peer(&self, _node: Node) -> Option<Node> {
+        None
+    }
+
+    /// Record the edges of the column and row tracks of a grid container, relative to its content box
+    ///
+    /// The default implementation discards them.
+    #[cfg(feature = "grid")]
+    fn record_grid_lines(&mut self, _node: Node, _columns: Vec<f32>, _rows: Vec<f32>) {}
+
+    /// Record the columns of its parent grid that a subgrid spans, to be returned by [`LayoutTree::subgrid_columns`]
+    ///
+    /// The default implementation discards them, in which case subgrids have no explicit columns.
+    #[cfg(feature = "grid")]
+    fn record_subgrid_columns(&mut self, _node: Node, _columns: SubgridColumns) {}
+
+    /// The columns most recently recorded for a subgrid with [`LayoutTree::record_subgrid_columns`]
+    #[cfg(feature = "grid")]
+    fn subgrid_columns(&self, _node: Node) -> Option<&SubgridColumns> {
+        None
+    }
+
+    /// Record the layout of a node as it was before being rounded
+    ///
+    /// The default implementation discards it.
+    #[cfg(feature = "test-helpers")]
+    fn record_unrounded_layout(&mut self, _node: Node, _layout: Layout) {}
+
+    /// Record the index of the flex line that a child of a flex container was placed on, or `None` if the child
+    /// did not take part in flex layout (e.g. because it is absolutely positioned or hidden)
+    ///
+    /// The default implementation discards it.
+    fn record_flex_line(&mut self, _node: Node, _line: Option<usize>) {}
+
+    /// Record a non-fatal problem encountered during layout
+    ///
+    /// The default implementation discards the warning.
+    fn record_warning(&mut self, _warning: LayoutWarning) {}
 }
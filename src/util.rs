@@ -0,0 +1,51 @@
+//! Helpers that expose parts of Taffy's style resolution, so that measure functions and renderers can resolve
+//! styles consistently with the layout algorithms
+
+use crate::geometry::Size;
+use crate::resolve::ResolveOrZero;
+use crate::style::LengthPercentage;
+
+/// Resolves a `gap` style against the inner size of its container
+///
+/// Percentage gaps resolve against the container's size in the same axis, and negative gaps are clamped to zero.
+pub fn resolve_gap(gap: Size<LengthPercentage>, container: Size<f32>) -> Size<f32> {
+    Size {
+        width: gap.width.resolve_or_zero(Some(container.width)).max(0.0),
+        height: gap.height.resolve_or_zero(Some(container.height)).max(0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_gap;
+    use crate::geometry::Size;
+    use crate::style::LengthPercentage;
+
+    #[test]
+    fn resolve_percentage_gap() {
+        let gap = Size { width: LengthPercentage::Percent(0.1), height: LengthPercentage::Points(5.0) };
+        let container = Size { width: 200.0, height: 100.0 };
+        assert_eq!(resolve_gap(gap, container), Size { width: 20.0, height: 5.0 });
+    }
+
+    #[test]
+    fn gap_is_never_auto() {
+        // Gaps are `LengthPercentage`s, so they have no `auto` value. Both the type annotation and the exhaustive match
+        // below stop compiling if gaps gain a value that does not resolve to a length.
+        let gap: Size<LengthPercentage> = crate::style::Style::DEFAULT.gap;
+        let container = Size { width: 200.0, height: 100.0 };
+        for value in [gap.width, LengthPercentage::Points(10.0), LengthPercentage::Percent(0.1)] {
+            let expected = match value {
+                LengthPercentage::Points(points) => points,
+                LengthPercentage::Percent(fraction) => fraction * container.width,
+            };
+            assert_eq!(resolve_gap(Size { width: value, height: gap.height }, container).width, expected);
+        }
+    }
+
+    #[test]
+    fn resolve_negative_gap() {
+        let gap = Size { width: LengthPercentage::Points(-5.0), height: LengthPercentage::Percent(-0.5) };
+        assert_eq!(resolve_gap(gap, Size { width: 200.0, height: 100.0 }), Size::ZERO);
+    }
+}
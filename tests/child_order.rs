@@ -0,0 +1,49 @@
+use taffy::prelude::*;
+
+/// Asserts that the `order` of each child's layout matches its index among its siblings
+fn assert_layout_order_matches_document_order(taffy: &Taffy, node: Node) {
+    for (index, child) in taffy.children(node).unwrap().into_iter().enumerate() {
+        assert_eq!(taffy.layout(child).unwrap().order, index as u32, "order of child {index}");
+    }
+}
+
+fn child_order_after_reordering(display: Display) {
+    let mut taffy = Taffy::new();
+    let leaf_style = Style { size: Size::from_points(10.0, 10.0), ..Default::default() };
+    let absolute = taffy.new_leaf(Style { position: Position::Absolute, ..leaf_style.clone() }).unwrap();
+    let in_flow_a = taffy.new_leaf(leaf_style.clone()).unwrap();
+    let hidden = taffy.new_leaf(Style { display: Display::None, ..leaf_style.clone() }).unwrap();
+    let in_flow_b = taffy.new_leaf(leaf_style).unwrap();
+    let node = taffy
+        .new_with_children(Style { display, ..Default::default() }, &[absolute, in_flow_a, hidden, in_flow_b])
+        .unwrap();
+
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_layout_order_matches_document_order(&taffy, node);
+
+    taffy.set_children(node, &[in_flow_b, hidden, absolute, in_flow_a]).unwrap();
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_layout_order_matches_document_order(&taffy, node);
+}
+
+#[test]
+fn flexbox_child_order_after_reordering() {
+    child_order_after_reordering(Display::Flex);
+}
+
+#[cfg(feature = "grid")]
+#[test]
+fn grid_child_order_after_reordering() {
+    child_order_after_reordering(Display::Grid);
+}
+
+#[test]
+fn child_order_is_sequential() {
+    let mut taffy = Taffy::new();
+    let children: Vec<Node> = (0..4).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+    let node = taffy.new_with_children(Style::default(), &children).unwrap();
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+    let orders: Vec<u32> = children.iter().map(|child| taffy.layout(*child).unwrap().order()).collect();
+    assert_eq!(orders, [0, 1, 2, 3]);
+}
@@ -18,7 +18,10 @@ fn grid_auto_fill_fixed_size() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![points(40f32), repeat(GridTrackRepetition::AutoFill, vec![points(40f32)])],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(40f32),
+                    repeat(GridTrackRepetition::AutoFill, vec![points(40f32)]),
+                ]),
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::Points(120f32),
                     height: taffy::style::Dimension::Points(120f32),
@@ -12,7 +12,10 @@ fn grid_auto_fill_with_empty_auto_track() {
                 display: taffy::style::Display::Grid,
                 justify_content: Some(taffy::style::JustifyContent::SpaceEvenly),
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![repeat(GridTrackRepetition::AutoFill, vec![points(40f32)])],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![repeat(
+                    GridTrackRepetition::AutoFill,
+                    vec![points(40f32)],
+                )]),
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::Points(120f32),
                     height: taffy::style::Dimension::Points(120f32),
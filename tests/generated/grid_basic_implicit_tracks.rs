@@ -19,7 +19,7 @@ fn grid_basic_implicit_tracks() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32)],
-                grid_template_columns: vec![points(40f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![points(40f32)]),
                 ..Default::default()
             },
             &[node0, node1],
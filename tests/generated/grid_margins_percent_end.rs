@@ -31,7 +31,11 @@ fn grid_margins_percent_end() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32), points(40f32), points(40f32)],
-                grid_template_columns: vec![points(20f32), points(20f32), points(20f32)],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![
+                    points(20f32),
+                    points(20f32),
+                    points(20f32),
+                ]),
                 ..Default::default()
             },
             &[node0, node1, node2, node3, node4, node5],
@@ -42,7 +42,7 @@ fn grid_min_content_flex_row() {
             taffy::style::Style {
                 display: taffy::style::Display::Grid,
                 grid_template_rows: vec![points(40f32)],
-                grid_template_columns: vec![min_content()],
+                grid_template_columns: taffy::style::GridTemplate::Tracks(vec![min_content()]),
                 ..Default::default()
             },
             &[node0],
@@ -474,4 +474,173 @@ mod measure {
 
         assert_eq!(NUM_MEASURES.load(Ordering::SeqCst), 2);
     }
+
+    #[test]
+    fn content_visibility_hidden_skips_children() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let mut taffy = Taffy::new();
+        static NUM_MEASURES: AtomicU32 = AtomicU32::new(0);
+
+        let grandchild = taffy
+            .new_leaf_with_measure(
+                Style { ..Default::default() },
+                MeasureFunc::Raw(|known_dimensions, _available_space| {
+                    NUM_MEASURES.fetch_add(1, Ordering::SeqCst);
+                    Size {
+                        width: known_dimensions.width.unwrap_or(50.0),
+                        height: known_dimensions.height.unwrap_or(50.0),
+                    }
+                }),
+            )
+            .unwrap();
+
+        let child = taffy.new_with_children(Style { ..Default::default() }, &[grandchild]).unwrap();
+
+        let node = taffy.new_with_children(Style { ..Default::default() }, &[child]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        let num_measures = NUM_MEASURES.load(Ordering::SeqCst);
+
+        // Hide the contents of the child, then change the grandchild in a way that would affect its layout
+        let hidden = Style {
+            content_visibility: ContentVisibility::Hidden,
+            padding: points(5.0),
+            border: Rect { left: LengthPercentage::Points(1.0), ..Rect::zero() },
+            ..Default::default()
+        };
+        taffy.set_style(child, hidden).unwrap();
+        taffy.set_style(grandchild, Style { size: Size::from_points(100.0, 100.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        // The child is sized as if it were empty, and the grandchild is neither measured nor laid out
+        assert_eq!(NUM_MEASURES.load(Ordering::SeqCst), num_measures);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 11.0, height: 10.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 50.0, height: 50.0 });
+
+        // Making the contents visible again recomputes them
+        taffy.set_style(child, Style { ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 100.0 });
+        assert_eq!(taffy.layout(grandchild).unwrap().size, Size { width: 100.0, height: 100.0 });
+    }
+
+    /// Measures text made of the words "a", "bbb" and "cc" laid out on one line, or wrapped onto one line per word
+    fn measure_words(known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> Size<f32> {
+        const WORD_LENGTHS: [f32; 3] = [10.0, 30.0, 20.0];
+        let longest_word: f32 = WORD_LENGTHS.iter().copied().fold(0.0, f32::max);
+        let whole_line: f32 = WORD_LENGTHS.iter().sum();
+        let width = known_dimensions.width.unwrap_or(match available_space.width {
+            AvailableSpace::MinContent => longest_word,
+            AvailableSpace::MaxContent => whole_line,
+            AvailableSpace::Definite(width) => width.clamp(longest_word, whole_line),
+        });
+        let line_count = if width >= whole_line { 1.0 } else { WORD_LENGTHS.len() as f32 };
+        Size { width, height: known_dimensions.height.unwrap_or(line_count * 10.0) }
+    }
+
+    #[test]
+    fn min_and_max_content_size_of_wrapping_text() {
+        let mut taffy = Taffy::new();
+        let text = taffy.new_leaf_with_measure(Style::default(), MeasureFunc::Raw(measure_words)).unwrap();
+        let toolbar = taffy
+            .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[text])
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[toolbar]).unwrap();
+
+        assert_eq!(taffy.min_content_size(toolbar).unwrap().width, 30.0);
+        assert_eq!(taffy.max_content_size(toolbar).unwrap().width, 60.0);
+
+        // Stored layouts are left untouched
+        for node in [root, toolbar, text] {
+            assert_eq!(taffy.layout(node).unwrap().size, Size::zero());
+        }
+    }
+
+    #[test]
+    fn compute_fit_shrinks_to_content() {
+        let mut taffy = Taffy::new();
+        let tooltip = taffy.new_leaf_with_measure(Style::default(), MeasureFunc::Raw(measure_words)).unwrap();
+
+        // Content narrower than the maximum keeps its max-content size
+        let size = taffy.compute_fit(tooltip, Size { width: 100.0, height: 100.0 }).unwrap();
+        assert_eq!(size, Size { width: 60.0, height: 10.0 });
+        assert_eq!(taffy.layout(tooltip).unwrap().size, size);
+
+        // Wider content is clamped to the maximum and wraps
+        let size = taffy.compute_fit(tooltip, Size { width: 45.0, height: 100.0 }).unwrap();
+        assert_eq!(size, Size { width: 45.0, height: 30.0 });
+
+        // But never shrinks below its min-content width
+        let size = taffy.compute_fit(tooltip, Size { width: 10.0, height: 100.0 }).unwrap();
+        assert_eq!(size.width, 30.0);
+    }
+
+    #[test]
+    fn compute_fit_clamps_content_taller_than_max() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style { size: Size::from_points(20.0, 200.0), flex_shrink: 0.0, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[child])
+            .unwrap();
+
+        let size = taffy.compute_fit(root, Size { width: 100.0, height: 50.0 }).unwrap();
+        assert_eq!(size, Size { width: 20.0, height: 50.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, size);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 20.0, height: 200.0 });
+    }
+
+    #[test]
+    fn measure_func_is_passed_max_lines() {
+        let mut taffy = Taffy::new();
+        let text = taffy
+            .new_leaf_with_measure(
+                Style { max_lines: Some(2), ..Default::default() },
+                MeasureFunc::RawWithMaxLines(|known_dimensions, available_space, max_lines| {
+                    let size = measure_words(known_dimensions, available_space);
+                    let max_height = max_lines.map(|max_lines| max_lines as f32 * 10.0);
+                    Size { width: size.width, height: max_height.map_or(size.height, |max| size.height.min(max)) }
+                }),
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    size: Size::from_points(40.0, 100.0),
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The text wraps onto three lines, but is clamped to two
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 40.0, height: 20.0 });
+
+        taffy.set_style(text, Style::default()).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 40.0, height: 30.0 });
+    }
+
+    #[test]
+    fn measure_func_sizes_container_with_children() {
+        let mut taffy = Taffy::new();
+        let child = taffy.new_leaf(Style { size: Size::from_points(20.0, 20.0), ..Default::default() }).unwrap();
+        let container = taffy.new_with_children(Style { flex_shrink: 1.0, ..Default::default() }, &[child]).unwrap();
+        taffy.set_measure(container, Some(MeasureFunc::Raw(|_, _| Size { width: 100.0, height: 30.0 }))).unwrap();
+        let root = taffy
+            .new_with_children(Style { size: Size::from_points(50.0, 50.0), ..Default::default() }, &[container])
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The measured minimum content size prevents the container from shrinking to fit the root
+        assert_eq!(taffy.min_content_size(container).unwrap().width, 100.0);
+        assert_eq!(taffy.layout(container).unwrap().size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 20.0, height: 20.0 });
+    }
 }
@@ -0,0 +1,41 @@
+//! Checks that a tree can be built and laid out without the standard library
+//!
+//! This crate depends on taffy with only the `alloc` and `grid` features enabled. It must be tested on its own
+//! (`cargo test -p taffy-no-std-test`) as otherwise cargo may unify taffy's features with those of other crates.
+#![no_std]
+
+extern crate alloc;
+
+// We always need std for the tests
+#[cfg(test)]
+extern crate std;
+
+use taffy::error::TaffyResult;
+use taffy::prelude::*;
+
+/// Lays out a fixed-size flexbox container with a single growing child, returning the size of the child
+pub fn layout_single_child(container_width: f32, container_height: f32) -> TaffyResult<Size<f32>> {
+    let mut taffy = Taffy::new();
+    let child = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() })?;
+    let root = taffy.new_with_children(
+        Style {
+            size: Size { width: Dimension::Points(container_width), height: Dimension::Points(container_height) },
+            ..Default::default()
+        },
+        &[child],
+    )?;
+    taffy.compute_layout(root, Size::MAX_CONTENT)?;
+    Ok(taffy.layout(child)?.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_layout_without_std() {
+        let size = layout_single_child(100.0, 50.0).unwrap();
+        assert_eq!(size.width, 100.0);
+        assert_eq!(size.height, 50.0);
+    }
+}
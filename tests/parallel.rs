@@ -0,0 +1,75 @@
+#![cfg(feature = "rayon")]
+
+use taffy::prelude::*;
+
+/// Builds a wide tree whose children are a mix of fixed-size containers (which are laid out in parallel)
+/// and auto-sized containers (which are not)
+fn build_wide_tree(taffy: &mut Taffy) -> Node {
+    let mut children = Vec::new();
+    for i in 0..64 {
+        let grandchildren: Vec<Node> = (0..4)
+            .map(|j| {
+                taffy
+                    .new_leaf(Style {
+                        flex_grow: j as f32,
+                        size: Size { width: Dimension::Auto, height: Dimension::Points(10.0 + j as f32) },
+                        ..Default::default()
+                    })
+                    .unwrap()
+            })
+            .collect();
+        let size = if i % 3 == 0 { Size::auto() } else { Size::from_points(40.0 + i as f32, 30.0) };
+        let child = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: if i % 2 == 0 { FlexDirection::Row } else { FlexDirection::Column },
+                    padding: Rect::points(2.0),
+                    size,
+                    ..Default::default()
+                },
+                &grandchildren,
+            )
+            .unwrap();
+        children.push(child);
+    }
+    taffy
+        .new_with_children(
+            Style {
+                flex_wrap: FlexWrap::Wrap,
+                size: Size { width: Dimension::Points(800.0), height: Dimension::Auto },
+                ..Default::default()
+            },
+            &children,
+        )
+        .unwrap()
+}
+
+fn collect_layouts(taffy: &Taffy, node: Node, out: &mut Vec<Layout>) {
+    out.push(*taffy.layout(node).unwrap());
+    for child in taffy.children(node).unwrap() {
+        collect_layouts(taffy, child, out);
+    }
+}
+
+#[test]
+fn parallel_layout_matches_serial_layout() {
+    let mut serial = Taffy::new();
+    let serial_root = build_wide_tree(&mut serial);
+    serial.compute_layout(serial_root, Size::MAX_CONTENT).unwrap();
+
+    let mut parallel = Taffy::new();
+    let parallel_root = build_wide_tree(&mut parallel);
+    parallel.compute_layout_parallel(parallel_root, Size::MAX_CONTENT).unwrap();
+
+    let mut serial_layouts = Vec::new();
+    collect_layouts(&serial, serial_root, &mut serial_layouts);
+    let mut parallel_layouts = Vec::new();
+    collect_layouts(&parallel, parallel_root, &mut parallel_layouts);
+
+    assert_eq!(serial_layouts.len(), parallel_layouts.len());
+    for (serial_layout, parallel_layout) in serial_layouts.iter().zip(parallel_layouts.iter()) {
+        assert_eq!(serial_layout.order, parallel_layout.order);
+        assert_eq!(serial_layout.size, parallel_layout.size);
+        assert_eq!(serial_layout.location, parallel_layout.location);
+    }
+}
@@ -77,4 +77,89 @@ mod root_constraints {
         assert_eq!(layout.size.width, 200.0);
         assert_eq!(layout.size.height, 200.0);
     }
+
+    #[test]
+    fn root_with_infinite_definite_available_space() {
+        let mut taffy = taffy::node::Taffy::new();
+        let child = taffy
+            .new_leaf_with_measure(
+                taffy::style::Style::default(),
+                taffy::node::MeasureFunc::Raw(|known_dimensions, available_space| {
+                    let width = match available_space.width {
+                        AvailableSpace::Definite(width) => width.min(30.0),
+                        AvailableSpace::MinContent => 10.0,
+                        AvailableSpace::MaxContent => 30.0,
+                    };
+                    taffy::geometry::Size { width: known_dimensions.width.unwrap_or(width), height: 10.0 }
+                }),
+            )
+            .unwrap();
+        let node = taffy
+            .new_with_children(
+                taffy::style::Style { flex_direction: taffy::style::FlexDirection::Column, ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                taffy::geometry::Size {
+                    width: AvailableSpace::Definite(f32::INFINITY),
+                    height: AvailableSpace::Definite(f32::INFINITY),
+                },
+            )
+            .unwrap();
+
+        // Infinite definite available space behaves as max-content, so the stretched child is sized by its content
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size.width, 30.0);
+        assert_eq!(layout.size.height, 10.0);
+        let layout = taffy.layout(child).unwrap();
+        assert_eq!(layout.size.width, 30.0);
+        assert_eq!(layout.size.height, 10.0);
+    }
+
+    #[test]
+    fn root_with_zero_available_space() {
+        let mut taffy = taffy::node::Taffy::new();
+        let child_style = taffy::style::Style {
+            flex_basis: taffy::style::Dimension::Points(50.0),
+            min_size: taffy::geometry::Size {
+                width: taffy::style::Dimension::Points(10.0),
+                height: taffy::style::Dimension::Points(5.0),
+            },
+            ..Default::default()
+        };
+        let children: Vec<_> = (0..3).map(|_| taffy.new_leaf(child_style.clone()).unwrap()).collect();
+        let node = taffy
+            .new_with_children(
+                taffy::style::Style {
+                    justify_content: Some(taffy::style::JustifyContent::SpaceAround),
+                    align_content: Some(taffy::style::AlignContent::SpaceBetween),
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                taffy::geometry::Size { width: AvailableSpace::Definite(0.0), height: AvailableSpace::Definite(0.0) },
+            )
+            .unwrap();
+
+        // The items shrink to their minimum sizes, and the negative free space does not produce NaN offsets
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size.width, 30.0);
+        assert_eq!(layout.size.height, 5.0);
+        for (index, child) in children.into_iter().enumerate() {
+            let layout = taffy.layout(child).unwrap();
+            assert_eq!(layout.size.width, 10.0);
+            assert_eq!(layout.size.height, 5.0);
+            assert_eq!(layout.location.x, index as f32 * 10.0);
+            assert_eq!(layout.location.y, 0.0);
+        }
+    }
 }
@@ -0,0 +1,34 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde {
+    use taffy::prelude::*;
+
+    #[test]
+    fn computed_layouts_serialize_to_json() {
+        let mut taffy = Taffy::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::Points(20.0), height: Dimension::Points(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    size: Size { width: Dimension::Points(100.0), height: Dimension::Points(50.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(taffy.layout(root).unwrap()).unwrap(),
+            r#"{"order":0,"size":{"width":100.0,"height":50.0},"location":{"x":0.0,"y":0.0},"scroll_size":{"width":100.0,"height":50.0}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(taffy.layout(child).unwrap()).unwrap(),
+            r#"{"order":0,"size":{"width":20.0,"height":10.0},"location":{"x":0.0,"y":0.0},"scroll_size":{"width":20.0,"height":10.0}}"#
+        );
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{Arc, Mutex};
+
+use taffy::prelude::*;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// The fields recorded on a `compute_node_layout` span
+#[derive(Default, Debug)]
+struct LayoutSpan {
+    node: String,
+    display: String,
+    cache_hit: Option<bool>,
+}
+
+impl Visit for LayoutSpan {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == "cache_hit" {
+            self.cache_hit = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "node" => self.node = format!("{value:?}"),
+            "display" => self.display = format!("{value:?}"),
+            _ => {}
+        }
+    }
+}
+
+/// A subscriber that records every `compute_node_layout` span. The id of each span is its index in `spans` plus one.
+#[derive(Default)]
+struct LayoutSpanRecorder {
+    spans: Arc<Mutex<Vec<LayoutSpan>>>,
+}
+
+impl Subscriber for LayoutSpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        assert_eq!(attributes.metadata().name(), "compute_node_layout");
+        let mut span = LayoutSpan::default();
+        attributes.record(&mut span);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        values.record(&mut self.spans.lock().unwrap()[span.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn compute_layout_emits_spans() {
+    let mut taffy = Taffy::new();
+    let leaf = taffy.new_leaf(Style { size: Size::from_points(10.0, 10.0), ..Default::default() }).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[leaf]).unwrap();
+
+    let recorder = LayoutSpanRecorder::default();
+    let spans = recorder.spans.clone();
+    tracing::subscriber::with_default(recorder, || taffy.compute_layout(root, Size::MAX_CONTENT).unwrap());
+
+    let spans = spans.lock().unwrap();
+    assert!(!spans.is_empty());
+    assert!(spans.iter().all(|span| span.display == "Flex"));
+    assert!(spans.iter().all(|span| span.node == root.to_string() || span.node == leaf.to_string()));
+    assert!(spans.iter().any(|span| span.node == root.to_string()));
+    assert!(spans.iter().any(|span| span.node == leaf.to_string()));
+    assert!(spans.iter().all(|span| span.cache_hit.is_some()));
+    assert!(spans.iter().any(|span| span.cache_hit == Some(true)));
+    assert!(spans.iter().any(|span| span.cache_hit == Some(false)));
+}